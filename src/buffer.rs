@@ -0,0 +1,309 @@
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "alloc")]
+use alloc::collections::VecDeque;
+use core::{
+    fmt::{self, Debug, Display},
+    hash::{Hash, Hasher},
+    mem::MaybeUninit,
+    slice,
+};
+
+/// A storage for the items buffered by [`MultiPeekable`] at one of its ends.
+///
+/// This trait is sealed and it cannot be implemented outside this crate. It is implemented by
+/// [`ArrayBuffer`] and, with the `alloc` feature, by [`VecDeque`].
+///
+/// [`MultiPeekable`]: crate::MultiPeekable
+/// [`VecDeque`]: https://doc.rust-lang.org/alloc/collections/struct.VecDeque.html
+pub trait Buffer<T>: sealed::Storage<T> {}
+
+pub(crate) mod sealed {
+    /// The actual interface of a [`Buffer`](super::Buffer).
+    ///
+    /// The _outer_ end of the buffer is the one closer to the respective end of the iterator,
+    /// the _inner_ end is the one closer to the items that have not been buffered yet. Index `0`
+    /// refers to the outermost item.
+    pub trait Storage<T> {
+        fn len(&self) -> usize;
+
+        fn is_full(&self) -> bool;
+
+        /// Returns the maximum number of items the buffer is able to store, or `None` if it is
+        /// unbounded.
+        fn capacity(&self) -> Option<usize>;
+
+        fn get(&self, index: usize) -> Option<&T>;
+
+        fn get_mut(&mut self, index: usize) -> Option<&mut T>;
+
+        /// Pushes an item at the inner end of the buffer.
+        ///
+        /// # Panics
+        ///
+        /// Panics if the buffer is full.
+        fn push_inner(&mut self, item: T);
+
+        /// Pushes an item at the outer end of the buffer.
+        ///
+        /// # Panics
+        ///
+        /// Panics if the buffer is full.
+        fn push_outer(&mut self, item: T);
+
+        fn pop_outer(&mut self) -> Option<T>;
+
+        fn pop_inner(&mut self) -> Option<T>;
+
+        /// Rearranges the buffer so that its items are contiguous, returning them as a slice
+        /// starting from the outermost one.
+        fn make_contiguous(&mut self) -> &mut [T];
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Buffer<T> for VecDeque<T> {}
+
+#[cfg(feature = "alloc")]
+impl<T> sealed::Storage<T> for VecDeque<T> {
+    #[inline]
+    fn len(&self) -> usize {
+        VecDeque::len(self)
+    }
+
+    #[inline]
+    fn is_full(&self) -> bool {
+        false
+    }
+
+    #[inline]
+    fn capacity(&self) -> Option<usize> {
+        None
+    }
+
+    #[inline]
+    fn get(&self, index: usize) -> Option<&T> {
+        VecDeque::get(self, index)
+    }
+
+    #[inline]
+    fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        VecDeque::get_mut(self, index)
+    }
+
+    #[inline]
+    fn push_inner(&mut self, item: T) {
+        self.push_back(item);
+    }
+
+    #[inline]
+    fn push_outer(&mut self, item: T) {
+        self.push_front(item);
+    }
+
+    #[inline]
+    fn pop_outer(&mut self) -> Option<T> {
+        self.pop_front()
+    }
+
+    #[inline]
+    fn pop_inner(&mut self) -> Option<T> {
+        self.pop_back()
+    }
+
+    #[inline]
+    fn make_contiguous(&mut self) -> &mut [T] {
+        VecDeque::make_contiguous(self)
+    }
+}
+
+/// A fixed-size ring buffer stored inline, able to hold up to `N` items.
+///
+/// This is the [`Buffer`] used by [`ArrayPeekable`], which does not need any allocation.
+///
+/// [`ArrayPeekable`]: crate::ArrayPeekable
+pub struct ArrayBuffer<T, const N: usize> {
+    items: [MaybeUninit<T>; N],
+    head: usize,
+    len: usize,
+}
+
+impl<T, const N: usize> ArrayBuffer<T, N> {
+    /// Creates an empty buffer.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            items: core::array::from_fn(|_| MaybeUninit::uninit()),
+            head: 0,
+            len: 0,
+        }
+    }
+
+    /// Returns the index inside `items` of the `index`th item of the buffer.
+    #[inline]
+    const fn physical_index(&self, index: usize) -> usize {
+        let until_end = N - self.head;
+        if index < until_end {
+            self.head + index
+        } else {
+            index - until_end
+        }
+    }
+
+    fn iter(&self) -> impl Iterator<Item = &T> {
+        (0..self.len).filter_map(|index| sealed::Storage::get(self, index))
+    }
+}
+
+impl<T, const N: usize> Buffer<T> for ArrayBuffer<T, N> {}
+
+impl<T, const N: usize> sealed::Storage<T> for ArrayBuffer<T, N> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline]
+    fn is_full(&self) -> bool {
+        self.len == N
+    }
+
+    #[inline]
+    fn capacity(&self) -> Option<usize> {
+        Some(N)
+    }
+
+    #[inline]
+    fn get(&self, index: usize) -> Option<&T> {
+        (index < self.len).then(|| {
+            // SAFETY: the first `len` items starting from `head` are always initialized.
+            unsafe { self.items[self.physical_index(index)].assume_init_ref() }
+        })
+    }
+
+    #[inline]
+    fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        (index < self.len).then(|| {
+            let index = self.physical_index(index);
+            // SAFETY: the first `len` items starting from `head` are always initialized.
+            unsafe { self.items[index].assume_init_mut() }
+        })
+    }
+
+    #[inline]
+    fn push_inner(&mut self, item: T) {
+        assert!(self.len < N, "buffer is full");
+        let index = self.physical_index(self.len);
+        self.items[index].write(item);
+        self.len += 1;
+    }
+
+    #[inline]
+    fn push_outer(&mut self, item: T) {
+        assert!(self.len < N, "buffer is full");
+        self.head = self.physical_index(N - 1);
+        self.items[self.head].write(item);
+        self.len += 1;
+    }
+
+    #[inline]
+    fn pop_outer(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+
+        // SAFETY: the buffer is not empty, therefore the item at `head` is initialized. Once it
+        // is read, it is left out of the initialized range.
+        let item = unsafe { self.items[self.head].assume_init_read() };
+        self.head = self.physical_index(1);
+        self.len -= 1;
+        Some(item)
+    }
+
+    #[inline]
+    fn pop_inner(&mut self) -> Option<T> {
+        self.len = self.len.checked_sub(1)?;
+
+        // SAFETY: the item was the last one of the initialized range, now it is left out.
+        Some(unsafe { self.items[self.physical_index(self.len)].assume_init_read() })
+    }
+
+    #[inline]
+    fn make_contiguous(&mut self) -> &mut [T] {
+        self.items.rotate_left(self.head);
+        self.head = 0;
+
+        // SAFETY: the first `len` items starting from `head` are always initialized, and `head`
+        // is now the start of the array. `MaybeUninit<T>` has the same layout of `T`.
+        unsafe { slice::from_raw_parts_mut(self.items.as_mut_ptr().cast::<T>(), self.len) }
+    }
+}
+
+impl<T, const N: usize> Drop for ArrayBuffer<T, N> {
+    fn drop(&mut self) {
+        while sealed::Storage::pop_outer(self).is_some() {}
+    }
+}
+
+impl<T, const N: usize> Default for ArrayBuffer<T, N> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize> Debug for ArrayBuffer<T, N>
+where
+    T: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T, const N: usize> Clone for ArrayBuffer<T, N>
+where
+    T: Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        let mut out = Self::new();
+        self.iter()
+            .for_each(|item| sealed::Storage::push_inner(&mut out, item.clone()));
+        out
+    }
+}
+
+impl<T, const N: usize> PartialEq for ArrayBuffer<T, N>
+where
+    T: PartialEq,
+{
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.iter().eq(other.iter())
+    }
+}
+
+impl<T, const N: usize> Eq for ArrayBuffer<T, N> where T: Eq {}
+
+impl<T, const N: usize> Hash for ArrayBuffer<T, N>
+where
+    T: Hash,
+{
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.len.hash(state);
+        self.iter().for_each(|item| item.hash(state));
+    }
+}
+
+/// The error returned when an item cannot be reached because a bounded buffer is full.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CapacityError;
+
+impl Display for CapacityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("the lookahead buffer is full")
+    }
+}
@@ -0,0 +1,122 @@
+use std::rc::Rc;
+
+use super::{sealed::Storage, *};
+
+#[test]
+fn array_buffer_wraps_around() {
+    let mut buffer = ArrayBuffer::<_, 3>::new();
+    assert_eq!(buffer.len(), 0);
+    assert!(!buffer.is_full());
+    assert_eq!(buffer.pop_outer(), None);
+    assert_eq!(buffer.pop_inner(), None);
+
+    buffer.push_inner(0);
+    buffer.push_inner(1);
+    buffer.push_inner(2);
+    assert!(buffer.is_full());
+    assert_eq!(buffer.get(2), Some(&2));
+    assert_eq!(buffer.get(3), None);
+
+    assert_eq!(buffer.pop_outer(), Some(0));
+    assert_eq!(buffer.pop_outer(), Some(1));
+    buffer.push_inner(3);
+    buffer.push_inner(4);
+    assert!(buffer.is_full());
+    assert_eq!(buffer.head, 2);
+    assert_eq!(format!("{buffer:?}"), "[2, 3, 4]");
+
+    *buffer.get_mut(1).unwrap() = 5;
+    assert_eq!(buffer.get(0), Some(&2));
+    assert_eq!(buffer.get(1), Some(&5));
+    assert_eq!(buffer.get(2), Some(&4));
+
+    assert_eq!(buffer.pop_inner(), Some(4));
+    assert_eq!(buffer.pop_inner(), Some(5));
+    assert_eq!(buffer.pop_outer(), Some(2));
+    assert_eq!(buffer.len(), 0);
+}
+
+#[test]
+#[should_panic = "buffer is full"]
+fn array_buffer_push_when_full() {
+    let mut buffer = ArrayBuffer::<_, 1>::new();
+    buffer.push_inner(0);
+    buffer.push_inner(1);
+}
+
+#[test]
+fn array_buffer_zero_capacity() {
+    let mut buffer = ArrayBuffer::<u8, 0>::new();
+    assert!(buffer.is_full());
+    assert_eq!(buffer.get(0), None);
+    assert_eq!(buffer.pop_outer(), None);
+    assert_eq!(buffer.pop_inner(), None);
+}
+
+#[test]
+fn array_buffer_drops_items() {
+    let item = Rc::new(());
+    let mut buffer = ArrayBuffer::<_, 3>::new();
+    for _ in 0..3 {
+        buffer.push_inner(Rc::clone(&item));
+    }
+    drop(buffer.pop_outer());
+    buffer.push_inner(Rc::clone(&item));
+    assert_eq!(Rc::strong_count(&item), 4);
+
+    let cloned = buffer.clone();
+    assert_eq!(Rc::strong_count(&item), 7);
+    assert_eq!(cloned, buffer);
+
+    drop(buffer);
+    drop(cloned);
+    assert_eq!(Rc::strong_count(&item), 1);
+}
+
+#[test]
+fn array_buffer_push_outer() {
+    let mut buffer = ArrayBuffer::<_, 3>::new();
+    buffer.push_outer(1);
+    assert_eq!(buffer.head, 2);
+    buffer.push_outer(0);
+    buffer.push_inner(2);
+    assert!(buffer.is_full());
+    assert_eq!(format!("{buffer:?}"), "[0, 1, 2]");
+
+    assert_eq!(buffer.pop_inner(), Some(2));
+    assert_eq!(buffer.pop_inner(), Some(1));
+    buffer.push_outer(-1);
+    assert_eq!(format!("{buffer:?}"), "[-1, 0]");
+    assert_eq!(buffer.pop_outer(), Some(-1));
+    assert_eq!(buffer.pop_outer(), Some(0));
+    assert_eq!(buffer.pop_outer(), None);
+}
+
+#[test]
+#[should_panic = "buffer is full"]
+fn array_buffer_push_outer_when_full() {
+    let mut buffer = ArrayBuffer::<_, 1>::new();
+    buffer.push_inner(0);
+    buffer.push_outer(1);
+}
+
+#[test]
+fn array_buffer_make_contiguous() {
+    let mut buffer = ArrayBuffer::<_, 4>::new();
+    assert_eq!(buffer.make_contiguous(), &[] as &[i32]);
+
+    buffer.push_inner(1);
+    buffer.push_inner(2);
+    buffer.push_outer(0);
+    buffer.push_outer(-1);
+    assert_eq!(buffer.head, 2);
+    assert_eq!(buffer.make_contiguous(), [-1, 0, 1, 2]);
+    assert_eq!(buffer.head, 0);
+
+    buffer.make_contiguous()[1] = 5;
+    assert_eq!(buffer.pop_outer(), Some(-1));
+    assert_eq!(buffer.pop_outer(), Some(5));
+    buffer.push_inner(3);
+    assert_eq!(buffer.make_contiguous(), [1, 2, 3]);
+    assert_eq!(format!("{buffer:?}"), "[1, 2, 3]");
+}
@@ -0,0 +1,102 @@
+#[cfg(test)]
+mod tests;
+
+use core::iter::FusedIterator;
+
+use crate::buffer::{sealed::Storage, ArrayBuffer};
+
+/// The items that were consumed while trying to get a chunk of `N` items, when the iterator ran
+/// out before the chunk was complete.
+///
+/// The items are stored in the same order they have in the iterator, regardless of the end they
+/// have been taken from. It is an iterator that yields them in that order.
+///
+/// This `struct` is created by [`DoubleEndedPeekable::next_chunk`] and
+/// [`DoubleEndedPeekable::next_back_chunk`].
+///
+/// [`DoubleEndedPeekable::next_chunk`]: crate::DoubleEndedPeekable::next_chunk
+/// [`DoubleEndedPeekable::next_back_chunk`]: crate::DoubleEndedPeekable::next_back_chunk
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PartialChunk<T, const N: usize> {
+    items: ArrayBuffer<T, N>,
+}
+
+impl<T, const N: usize> PartialChunk<T, N> {
+    /// Returns the number of items, which is always less than `N`.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Returns `true` if no items were consumed.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.items.len() == 0
+    }
+
+    /// Returns a reference to the item at position `index`, if any.
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.items.get(index)
+    }
+}
+
+impl<T, const N: usize> Iterator for PartialChunk<T, N> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.items.pop_outer()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.items.len();
+        (len, Some(len))
+    }
+}
+
+impl<T, const N: usize> DoubleEndedIterator for PartialChunk<T, N> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.items.pop_inner()
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for PartialChunk<T, N> {}
+
+impl<T, const N: usize> FusedIterator for PartialChunk<T, N> {}
+
+/// Takes `N` items from `next`, which returns them in the same order they have in the iterator.
+#[inline]
+pub(crate) fn collect_front<T, const N: usize>(
+    next: impl FnMut() -> Option<T>,
+) -> Result<[T; N], PartialChunk<T, N>> {
+    collect(next, Storage::push_inner)
+}
+
+/// Takes `N` items from `next_back`, which returns them in reverse order.
+#[inline]
+pub(crate) fn collect_back<T, const N: usize>(
+    next_back: impl FnMut() -> Option<T>,
+) -> Result<[T; N], PartialChunk<T, N>> {
+    collect(next_back, Storage::push_outer)
+}
+
+fn collect<T, const N: usize>(
+    mut next: impl FnMut() -> Option<T>,
+    push: impl Fn(&mut ArrayBuffer<T, N>, T),
+) -> Result<[T; N], PartialChunk<T, N>> {
+    let mut items = ArrayBuffer::new();
+    while !items.is_full() {
+        match next() {
+            Some(item) => push(&mut items, item),
+            None => return Err(PartialChunk { items }),
+        }
+    }
+
+    Ok(core::array::from_fn(|_| match items.pop_outer() {
+        Some(item) => item,
+        None => unreachable!("the chunk is full"),
+    }))
+}
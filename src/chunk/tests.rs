@@ -0,0 +1,67 @@
+use std::rc::Rc;
+
+use crate::{DoubleEndedPeekable, DoubleEndedPeekableExt};
+
+#[test]
+fn next_chunk_drains_the_peeked_items() {
+    let mut iter = (0..6).double_ended_peekable();
+    assert_eq!(iter.peek(), Some(&0));
+    assert_eq!(iter.peek_back(), Some(&5));
+
+    assert_eq!(iter.next_chunk::<2>(), Ok([0, 1]));
+    // The item peeked from the back is the last one of the chunk
+    assert_eq!(iter.next_back_chunk::<2>(), Ok([4, 5]));
+    assert_eq!(iter.next_chunk::<0>(), Ok([]));
+
+    assert_eq!(iter.peek(), Some(&2));
+    assert_eq!(iter.peek_back(), Some(&3));
+    assert_eq!(iter.clone().next_chunk::<2>(), Ok([2, 3]));
+    assert_eq!(iter.next_back_chunk::<2>(), Ok([2, 3]));
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn partial_chunk() {
+    let mut iter = (0..3).double_ended_peekable();
+    assert_eq!(iter.peek_back(), Some(&2));
+    let mut partial = iter.next_chunk::<5>().unwrap_err();
+    assert_eq!(partial.len(), 3);
+    assert!(!partial.is_empty());
+    assert_eq!(partial.get(0), Some(&0));
+    assert_eq!(partial.get(3), None);
+    assert_eq!(partial.next_back(), Some(2));
+    assert_eq!(partial.collect::<Vec<_>>(), [0, 1]);
+    assert_eq!(iter.next(), None);
+
+    let mut iter = DoubleEndedPeekable::from_parts(Some(Some(0)), 1..3, None);
+    let partial = iter.next_back_chunk::<5>().unwrap_err();
+    assert_eq!(format!("{partial:?}"), "PartialChunk { items: [0, 1, 2] }");
+    assert!(partial.clone().eq([0, 1, 2]));
+    assert!(partial.rev().eq([2, 1, 0]));
+
+    let partial = (0..0)
+        .double_ended_peekable()
+        .next_chunk::<1>()
+        .unwrap_err();
+    assert!(partial.is_empty());
+    assert_eq!(partial.size_hint(), (0, Some(0)));
+}
+
+#[test]
+fn chunks_do_not_leak() {
+    let item = Rc::new(());
+    let mut iter = (0..5)
+        .map(|_| Rc::clone(&item))
+        .collect::<Vec<_>>()
+        .into_iter()
+        .double_ended_peekable();
+
+    let chunk = iter.next_chunk::<2>().unwrap();
+    assert_eq!(Rc::strong_count(&item), 6);
+    drop(chunk);
+
+    let partial = iter.next_back_chunk::<4>().unwrap_err();
+    assert_eq!(Rc::strong_count(&item), 4);
+    drop(partial);
+    assert_eq!(Rc::strong_count(&item), 1);
+}
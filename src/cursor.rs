@@ -0,0 +1,298 @@
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "alloc")]
+use alloc::collections::VecDeque;
+use core::fmt::{self, Debug};
+
+use crate::{
+    buffer::{ArrayBuffer, Buffer},
+    DoubleEndedPeekable, DoubleEndedPeekableExt,
+};
+
+/// A cursor over the items of an iterator, able to step back over the items it has already
+/// passed.
+///
+/// The cursor sits between two items: the _next_ one, returned by [`peek`], and the _previous_
+/// one, returned by [`peek_prev`]. Moving the cursor forward using [`advance`] records the item it
+/// passes over in a history buffer of type `H`, then [`retreat`] moves the cursor back over the
+/// recorded items. When a bounded history is full, the oldest item is forgotten.
+///
+/// You probably want to use one of its specializations:
+///
+/// - [`ArrayCursor`], which uses a fixed-size history stored inline and does not allocate;
+/// - `DequeCursor`, which uses an unbounded history and requires the `alloc` feature.
+///
+/// [`peek`]: Self::peek
+/// [`peek_prev`]: Self::peek_prev
+/// [`advance`]: Self::advance
+/// [`retreat`]: Self::retreat
+pub struct Cursor<I: Iterator, H> {
+    peekable: DoubleEndedPeekable<I>,
+    history: H,
+    rewound: H,
+    position: usize,
+}
+
+/// A [`Cursor`] that remembers up to `N` items behind it, without allocating.
+///
+/// This type is created by the [`array_cursor`] method on [`DoubleEndedPeekableExt`].
+///
+/// [`array_cursor`]: crate::DoubleEndedPeekableExt::array_cursor
+pub type ArrayCursor<I, const N: usize> = Cursor<I, ArrayBuffer<<I as Iterator>::Item, N>>;
+
+/// A [`Cursor`] that remembers all the items behind it.
+///
+/// This type is created by the [`deque_cursor`] method on [`DoubleEndedPeekableExt`], and it is
+/// only available with the `alloc` feature.
+///
+/// [`deque_cursor`]: crate::DoubleEndedPeekableExt::deque_cursor
+#[cfg(feature = "alloc")]
+pub type DequeCursor<I> = Cursor<I, VecDeque<<I as Iterator>::Item>>;
+
+impl<I, H> Cursor<I, H>
+where
+    I: Iterator,
+    H: Buffer<I::Item> + Default,
+{
+    #[inline]
+    pub(crate) fn new(iter: I) -> Self {
+        Self {
+            peekable: iter.double_ended_peekable(),
+            history: H::default(),
+            rewound: H::default(),
+            position: 0,
+        }
+    }
+}
+
+impl<I, H> Cursor<I, H>
+where
+    I: Iterator,
+    H: Buffer<I::Item>,
+{
+    /// Returns the number of items before the cursor, counted from the beginning of the
+    /// iteration.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use double_ended_peekable::DoubleEndedPeekableExt;
+    ///
+    /// let mut cursor = ['a', 'b'].into_iter().array_cursor::<1>();
+    /// assert_eq!(cursor.position(), 0);
+    ///
+    /// assert!(cursor.advance());
+    /// assert!(cursor.advance());
+    /// assert_eq!(cursor.position(), 2);
+    ///
+    /// // The end of the iteration has been reached
+    /// assert!(!cursor.advance());
+    /// assert_eq!(cursor.position(), 2);
+    ///
+    /// assert!(cursor.retreat());
+    /// assert_eq!(cursor.position(), 1);
+    /// ```
+    #[inline]
+    pub const fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Moves the cursor forward over the next item, recording it in the history. Returns `false`
+    /// if there are no items left.
+    ///
+    /// If the history is full, the oldest item is forgotten and it cannot be reached anymore by
+    /// [`retreat`].
+    ///
+    /// [`retreat`]: Self::retreat
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use double_ended_peekable::DoubleEndedPeekableExt;
+    ///
+    /// let mut cursor = [1, 2, 3].into_iter().array_cursor::<1>();
+    ///
+    /// assert!(cursor.advance());
+    /// assert!(cursor.advance());
+    /// assert_eq!(cursor.peek_prev(), Some(&2));
+    ///
+    /// // Only one item is remembered
+    /// assert!(cursor.retreat());
+    /// assert!(!cursor.retreat());
+    /// assert_eq!(cursor.peek(), Some(&2));
+    /// ```
+    pub fn advance(&mut self) -> bool {
+        let item = match self.rewound.pop_outer() {
+            Some(item) => item,
+            None => match self.peekable.next() {
+                Some(item) => item,
+                None => return false,
+            },
+        };
+
+        if self.history.is_full() {
+            self.history.pop_outer();
+        }
+
+        // A history without capacity is still full, the item is just dropped.
+        if !self.history.is_full() {
+            self.history.push_inner(item);
+        }
+
+        self.position += 1;
+        true
+    }
+
+    /// Moves the cursor back over the last item recorded in the history. Returns `false` if the
+    /// history is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use double_ended_peekable::DoubleEndedPeekableExt;
+    ///
+    /// let mut cursor = [1, 2, 3].into_iter().array_cursor::<2>();
+    ///
+    /// assert!(!cursor.retreat());
+    /// assert!(cursor.advance());
+    /// assert!(cursor.advance());
+    ///
+    /// assert!(cursor.retreat());
+    /// assert_eq!(cursor.peek(), Some(&2));
+    /// assert_eq!(cursor.peek_prev(), Some(&1));
+    /// ```
+    pub fn retreat(&mut self) -> bool {
+        match self.history.pop_inner() {
+            Some(item) => {
+                // The rewound items have been taken from the history, therefore they always fit
+                // in a buffer of the same type.
+                self.rewound.push_outer(item);
+                self.position -= 1;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Returns a reference to the item after the cursor, without moving it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use double_ended_peekable::DoubleEndedPeekableExt;
+    ///
+    /// let mut cursor = [1, 2].into_iter().array_cursor::<1>();
+    /// assert_eq!(cursor.peek(), Some(&1));
+    ///
+    /// cursor.advance();
+    /// cursor.advance();
+    /// assert_eq!(cursor.peek(), None);
+    /// ```
+    #[inline]
+    pub fn peek(&mut self) -> Option<&I::Item> {
+        if self.rewound.len() == 0 {
+            self.peekable.peek()
+        } else {
+            self.rewound.get(0)
+        }
+    }
+
+    /// Returns a mutable reference to the item after the cursor, without moving it.
+    #[inline]
+    pub fn peek_mut(&mut self) -> Option<&mut I::Item> {
+        if self.rewound.len() == 0 {
+            self.peekable.peek_mut()
+        } else {
+            self.rewound.get_mut(0)
+        }
+    }
+
+    /// Returns a reference to the item before the cursor, if it is still in the history.
+    #[inline]
+    pub fn peek_prev(&self) -> Option<&I::Item> {
+        self.history.get(self.history.len().checked_sub(1)?)
+    }
+}
+
+impl<I, H> Cursor<I, H>
+where
+    I: DoubleEndedIterator,
+    H: Buffer<I::Item>,
+{
+    /// Returns a reference to the last item of the iteration, without moving the cursor.
+    ///
+    /// If the cursor is after the last item, `None` is returned, even if the last item is still
+    /// in the history.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use double_ended_peekable::DoubleEndedPeekableExt;
+    ///
+    /// let mut cursor = [1, 2, 3].into_iter().array_cursor::<3>();
+    /// assert_eq!(cursor.peek_back(), Some(&3));
+    ///
+    /// while cursor.advance() {}
+    /// assert_eq!(cursor.peek_back(), None);
+    ///
+    /// // Items can be found again after stepping back
+    /// cursor.retreat();
+    /// assert_eq!(cursor.peek_back(), Some(&3));
+    /// ```
+    #[inline]
+    pub fn peek_back(&mut self) -> Option<&I::Item> {
+        if self.peekable.peek_back().is_some() {
+            self.peekable.peek_back()
+        } else {
+            self.rewound.get(self.rewound.len().checked_sub(1)?)
+        }
+    }
+
+    /// Returns a mutable reference to the last item of the iteration, without moving the cursor.
+    ///
+    /// See [`peek_back`] for more information.
+    ///
+    /// [`peek_back`]: Self::peek_back
+    #[inline]
+    pub fn peek_back_mut(&mut self) -> Option<&mut I::Item> {
+        if self.peekable.peek_back().is_some() {
+            self.peekable.peek_back_mut()
+        } else {
+            self.rewound.get_mut(self.rewound.len().checked_sub(1)?)
+        }
+    }
+}
+
+impl<I, H> Debug for Cursor<I, H>
+where
+    I: Iterator + Debug,
+    I::Item: Debug,
+    H: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Cursor")
+            .field("peekable", &self.peekable)
+            .field("history", &self.history)
+            .field("rewound", &self.rewound)
+            .field("position", &self.position)
+            .finish()
+    }
+}
+
+impl<I, H> Clone for Cursor<I, H>
+where
+    I: Iterator + Clone,
+    I::Item: Clone,
+    H: Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            peekable: self.peekable.clone(),
+            history: self.history.clone(),
+            rewound: self.rewound.clone(),
+            position: self.position,
+        }
+    }
+}
@@ -0,0 +1,180 @@
+use std::{cell::Cell, rc::Rc};
+
+use crate::{buffer::sealed::Storage, DoubleEndedPeekableExt};
+
+#[test]
+fn advance_and_retreat() {
+    let mut cursor = [0, 1, 2].into_iter().array_cursor::<4>();
+
+    assert!(!cursor.retreat());
+    assert!(cursor.advance());
+    assert!(cursor.advance());
+    assert!(cursor.advance());
+    assert!(!cursor.advance());
+    assert_eq!(cursor.position(), 3);
+    assert_eq!(cursor.peek(), None);
+    assert_eq!(cursor.peek_prev(), Some(&2));
+
+    assert!(cursor.retreat());
+    assert!(cursor.retreat());
+    assert!(cursor.retreat());
+    assert!(!cursor.retreat());
+    assert_eq!(cursor.position(), 0);
+    assert_eq!(cursor.peek(), Some(&0));
+    assert_eq!(cursor.peek_prev(), None);
+
+    assert!(cursor.advance());
+    assert_eq!(cursor.peek(), Some(&1));
+    assert_eq!(cursor.peek_prev(), Some(&0));
+    assert_eq!(cursor.history.len(), 1);
+    assert_eq!(cursor.rewound.len(), 2);
+}
+
+#[test]
+fn bounded_history() {
+    let mut cursor = (0..10).array_cursor::<2>();
+
+    for _ in 0..5 {
+        assert!(cursor.advance());
+    }
+    assert_eq!(cursor.position(), 5);
+
+    assert!(cursor.retreat());
+    assert!(cursor.retreat());
+    assert!(!cursor.retreat());
+    assert_eq!(cursor.position(), 3);
+    assert_eq!(cursor.peek(), Some(&3));
+
+    // Moving forward over the rewound items does not forget anything
+    assert!(cursor.advance());
+    assert!(cursor.advance());
+    assert!(cursor.retreat());
+    assert!(cursor.retreat());
+    assert!(!cursor.retreat());
+    assert_eq!(cursor.position(), 3);
+
+    // Moving past the rewound items forgets the oldest ones again
+    for _ in 0..3 {
+        assert!(cursor.advance());
+    }
+    assert_eq!(cursor.peek(), Some(&6));
+    assert!(cursor.retreat());
+    assert!(cursor.retreat());
+    assert!(!cursor.retreat());
+    assert_eq!(cursor.peek(), Some(&4));
+}
+
+#[test]
+fn zero_capacity() {
+    let mut cursor = [0, 1].into_iter().array_cursor::<0>();
+
+    assert!(cursor.advance());
+    assert!(!cursor.retreat());
+    assert_eq!(cursor.peek_prev(), None);
+    assert_eq!(cursor.peek(), Some(&1));
+    assert!(cursor.advance());
+    assert!(!cursor.advance());
+    assert_eq!(cursor.position(), 2);
+}
+
+#[test]
+fn peek_mut() {
+    let mut cursor = [0, 1].into_iter().array_cursor::<2>();
+
+    *cursor.peek_mut().unwrap() = 10;
+    cursor.advance();
+    cursor.retreat();
+    assert_eq!(cursor.peek(), Some(&10));
+
+    *cursor.peek_mut().unwrap() = 20;
+    cursor.advance();
+    assert_eq!(cursor.peek_prev(), Some(&20));
+}
+
+#[test]
+fn peek_back() {
+    let mut cursor = [0, 1, 2].into_iter().array_cursor::<4>();
+
+    assert_eq!(cursor.peek_back(), Some(&2));
+    cursor.advance();
+    cursor.advance();
+    assert_eq!(cursor.peek_back(), Some(&2));
+    cursor.advance();
+    assert_eq!(cursor.peek_back(), None);
+
+    cursor.retreat();
+    cursor.retreat();
+    assert_eq!(cursor.peek(), Some(&1));
+    assert_eq!(cursor.peek_back(), Some(&2));
+
+    *cursor.peek_back_mut().unwrap() = 20;
+    cursor.advance();
+    assert_eq!(cursor.peek(), Some(&20));
+    assert_eq!(cursor.peek_back(), Some(&20));
+}
+
+#[test]
+fn peek_back_before_advancing() {
+    let mut cursor = [0, 1].into_iter().array_cursor::<2>();
+
+    // The last item is peeked from the back, it is then found from the front as well
+    assert_eq!(cursor.peek_back(), Some(&1));
+    assert!(cursor.advance());
+    assert_eq!(cursor.peek(), Some(&1));
+    assert!(cursor.advance());
+    assert!(!cursor.advance());
+    assert_eq!(cursor.peek_back(), None);
+
+    assert!(cursor.retreat());
+    assert_eq!(cursor.peek_back(), Some(&1));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn deque_cursor() {
+    let mut cursor = (0..100).deque_cursor();
+
+    while cursor.advance() {}
+    assert_eq!(cursor.position(), 100);
+
+    while cursor.retreat() {}
+    assert_eq!(cursor.position(), 0);
+    assert_eq!(cursor.peek(), Some(&0));
+    assert_eq!(cursor.peek_back(), Some(&99));
+}
+
+#[test]
+fn drops_forgotten_items() {
+    let drops = Rc::new(Cell::new(0));
+
+    struct Guard(Rc<Cell<usize>>);
+
+    impl Drop for Guard {
+        fn drop(&mut self) {
+            self.0.set(self.0.get() + 1);
+        }
+    }
+
+    let mut cursor = (0..5).map(|_| Guard(Rc::clone(&drops))).array_cursor::<2>();
+
+    cursor.advance();
+    cursor.advance();
+    cursor.advance();
+    assert_eq!(drops.get(), 1);
+    cursor.retreat();
+    assert_eq!(drops.get(), 1);
+
+    drop(cursor);
+    assert_eq!(drops.get(), 3);
+}
+
+#[test]
+fn clone_and_debug() {
+    let mut cursor = [0, 1].into_iter().array_cursor::<2>();
+    cursor.advance();
+
+    let mut cloned = cursor.clone();
+    assert_eq!(cloned.position(), 1);
+    assert_eq!(cloned.peek(), Some(&1));
+    assert!(format!("{cursor:?}").starts_with("Cursor {"));
+}
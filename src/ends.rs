@@ -0,0 +1,140 @@
+#[cfg(test)]
+mod tests;
+
+/// The items at the ends of a double-ended iterator.
+///
+/// When only one item is left, the front and the back of the iterator are the same item, which is
+/// reported as [`Single`](Ends::Single).
+///
+/// This `enum` is returned by [`DoubleEndedPeekable::peek_front_back`],
+/// [`DoubleEndedPeekable::next_front_back`] and their variants.
+///
+/// [`DoubleEndedPeekable::peek_front_back`]: crate::DoubleEndedPeekable::peek_front_back
+/// [`DoubleEndedPeekable::next_front_back`]: crate::DoubleEndedPeekable::next_front_back
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Ends<T> {
+    /// There are no items left.
+    Empty,
+
+    /// There is only one item left, which is both the front and the back one.
+    Single(T),
+
+    /// The front and the back items, which are distinct.
+    Pair(T, T),
+}
+
+impl<T> Ends<T> {
+    /// Returns the number of distinct items, from `0` to `2`.
+    #[inline]
+    pub const fn len(&self) -> usize {
+        match self {
+            Self::Empty => 0,
+            Self::Single(_) => 1,
+            Self::Pair(_, _) => 2,
+        }
+    }
+
+    /// Returns `true` if there are no items.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        matches!(self, Self::Empty)
+    }
+
+    /// Returns a reference to the front item, if any.
+    ///
+    /// In case of [`Single`](Ends::Single), this is the same item returned by [`back`].
+    ///
+    /// [`back`]: Self::back
+    #[inline]
+    pub const fn front(&self) -> Option<&T> {
+        match self {
+            Self::Empty => None,
+            Self::Single(front) | Self::Pair(front, _) => Some(front),
+        }
+    }
+
+    /// Returns a reference to the back item, if any.
+    ///
+    /// In case of [`Single`](Ends::Single), this is the same item returned by [`front`].
+    ///
+    /// [`front`]: Self::front
+    #[inline]
+    pub const fn back(&self) -> Option<&T> {
+        match self {
+            Self::Empty => None,
+            Self::Single(back) | Self::Pair(_, back) => Some(back),
+        }
+    }
+
+    /// Converts from `&Ends<T>` to `Ends<&T>`.
+    #[inline]
+    pub const fn as_ref(&self) -> Ends<&T> {
+        match self {
+            Self::Empty => Ends::Empty,
+            Self::Single(item) => Ends::Single(item),
+            Self::Pair(front, back) => Ends::Pair(front, back),
+        }
+    }
+
+    /// Converts from `&mut Ends<T>` to `Ends<&mut T>`.
+    #[inline]
+    pub fn as_mut(&mut self) -> Ends<&mut T> {
+        match self {
+            Self::Empty => Ends::Empty,
+            Self::Single(item) => Ends::Single(item),
+            Self::Pair(front, back) => Ends::Pair(front, back),
+        }
+    }
+
+    /// Maps the items using the given function, applied first to the front item and then to the
+    /// back one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use double_ended_peekable::Ends;
+    ///
+    /// assert_eq!(Ends::Pair(1, 2).map(|x| x * 10), Ends::Pair(10, 20));
+    /// assert_eq!(Ends::Single(1).map(|x| x * 10), Ends::Single(10));
+    /// ```
+    #[inline]
+    pub fn map<U, F>(self, mut f: F) -> Ends<U>
+    where
+        F: FnMut(T) -> U,
+    {
+        match self {
+            Self::Empty => Ends::Empty,
+            Self::Single(item) => Ends::Single(f(item)),
+            Self::Pair(front, back) => {
+                let front = f(front);
+                Ends::Pair(front, f(back))
+            }
+        }
+    }
+}
+
+/// Which ends of a double-ended iterator should be advanced.
+///
+/// This `enum` is returned by the functions passed to [`DoubleEndedPeekable::next_ends_with`] and
+/// [`DoubleEndedPeekable::two_pointer`].
+///
+/// [`DoubleEndedPeekable::next_ends_with`]: crate::DoubleEndedPeekable::next_ends_with
+/// [`DoubleEndedPeekable::two_pointer`]: crate::DoubleEndedPeekable::two_pointer
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Take {
+    /// Advance the front only.
+    Front,
+
+    /// Advance the back only.
+    Back,
+
+    /// Advance both the front and the back.
+    Both,
+
+    /// Do not advance the iterator.
+    ///
+    /// It also stops the scan of [`DoubleEndedPeekable::two_pointer`].
+    ///
+    /// [`DoubleEndedPeekable::two_pointer`]: crate::DoubleEndedPeekable::two_pointer
+    None,
+}
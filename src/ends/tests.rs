@@ -0,0 +1,42 @@
+use super::*;
+
+#[test]
+fn accessors() {
+    let empty = Ends::<i32>::Empty;
+    assert_eq!(empty.len(), 0);
+    assert!(empty.is_empty());
+    assert_eq!(empty.front(), None);
+    assert_eq!(empty.back(), None);
+
+    let single = Ends::Single(1);
+    assert_eq!(single.len(), 1);
+    assert!(!single.is_empty());
+    assert_eq!(single.front(), Some(&1));
+    assert_eq!(single.back(), Some(&1));
+
+    let pair = Ends::Pair(1, 2);
+    assert_eq!(pair.len(), 2);
+    assert_eq!(pair.front(), Some(&1));
+    assert_eq!(pair.back(), Some(&2));
+    assert_eq!(pair.as_ref(), Ends::Pair(&1, &2));
+}
+
+#[test]
+fn map_and_as_mut() {
+    let mut pair = Ends::Pair(1, 2);
+    if let Ends::Pair(front, back) = pair.as_mut() {
+        *front += 10;
+        *back += 20;
+    }
+    assert_eq!(pair, Ends::Pair(11, 22));
+
+    let mut calls = Vec::new();
+    let mapped = pair.map(|item| {
+        calls.push(item);
+        item.to_string()
+    });
+    assert_eq!(mapped, Ends::Pair("11".to_string(), "22".to_string()));
+    assert_eq!(calls, [11, 22]);
+
+    assert_eq!(Ends::<i32>::Empty.map(|item| item + 1), Ends::Empty);
+}
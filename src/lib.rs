@@ -24,6 +24,28 @@
 //!
 //! Check [`DoubleEndedPeekable`] documentation for additional information.
 //!
+//! # Lookahead of multiple items
+//!
+//! [`DoubleEndedPeekable`] stores at most one peeked item for each end. When you need to look
+//! further, use [`MultiPeekable`]:
+//!
+//! ```
+//! use double_ended_peekable::DoubleEndedPeekableExt;
+//!
+//! // Up to 2 items from the front and 3 items from the back, without allocating
+//! let mut iter = [1, 2, 3, 4].into_iter().array_peekable::<2, 3>();
+//! assert_eq!(iter.peek_nth(1), Some(&2));
+//! assert_eq!(iter.peek_back_nth(2), Some(&2));
+//! ```
+//!
+//! # Cargo features
+//!
+//! By default this crate is `no_std` and it does not allocate. The following features are
+//! available:
+//!
+//! - `alloc`: enables `DequePeekable`, a [`MultiPeekable`] with unbounded buffers backed by
+//!   `VecDeque`.
+//!
 //! # Rationale
 //!
 //! It is possible to use [`Peekable`] on double-ended iterators using `.rev().peekable()`:
@@ -49,20 +71,53 @@
 //! Therefore, creating a _rev-peekable_ iterator on the fly is risky because there is a good
 //! chance a peeked element is going to be accidentally lost.
 //!
-//! This tiny crate exposes a simple but powerful abstraction that is hard to misuse.
+//! This tiny crate exposes a simple but powerful abstraction that is hard to misuse. When the
+//! adapter is only needed for a while over a borrowed iterator, [`with_double_ended_peekable`]
+//! hands the peeked items back to the caller at the end of the scope.
 //!
 //! [`Peekable`]: core::iter::Peekable
 
 #![cfg_attr(not(test), no_std)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+mod buffer;
+mod chunk;
+mod cursor;
+mod ends;
+mod multi;
+mod next_while;
+mod peek;
+mod rejected;
+mod reversed;
+mod scoped;
 #[cfg(test)]
 mod tests;
+mod view;
+
+pub use buffer::{ArrayBuffer, Buffer, CapacityError};
+pub use chunk::PartialChunk;
+#[cfg(feature = "alloc")]
+pub use cursor::DequeCursor;
+pub use cursor::{ArrayCursor, Cursor};
+pub use ends::{Ends, Take};
+pub use multi::{ArrayPeekable, MultiPeekable};
+#[cfg(feature = "alloc")]
+pub use multi::{DequePeekable, PeekRange};
+pub use next_while::{NextBackWhile, NextWhile};
+pub use peek::{PeekBack, PeekFront};
+pub use rejected::Rejected;
+pub use reversed::Reversed;
+pub use scoped::{with_double_ended_peekable, Leftovers};
+pub use view::{BackView, FrontView};
 
 use core::{
     fmt::{self, Debug},
     hash::{Hash, Hasher},
     hint::unreachable_unchecked,
-    mem,
+    iter::{Chain, FusedIterator, Peekable},
+    mem, option,
 };
 
 /// An _extension trait_ to create [`DoubleEndedPeekable`].
@@ -76,6 +131,34 @@ pub trait DoubleEndedPeekableExt<I: Iterator> {
     ///
     /// [`Peekable`]: core::iter::Peekable
     fn double_ended_peekable(self) -> DoubleEndedPeekable<I>;
+
+    /// Creates an iterator which works similarly to [`DoubleEndedPeekable`], but it is able to
+    /// look ahead up to `FRONT` items from the front and `BACK` items from the back, without
+    /// allocating.
+    ///
+    /// See [`ArrayPeekable`] for more information.
+    fn array_peekable<const FRONT: usize, const BACK: usize>(self)
+        -> ArrayPeekable<I, FRONT, BACK>;
+
+    /// Creates an iterator which works similarly to [`DoubleEndedPeekable`], but it is able to
+    /// look ahead an arbitrary number of items from both the ends.
+    ///
+    /// See [`DequePeekable`] for more information.
+    #[cfg(feature = "alloc")]
+    fn deque_peekable(self) -> DequePeekable<I>;
+
+    /// Creates a cursor over the items of the iterator, which is able to step back over up to
+    /// `N` items, without allocating.
+    ///
+    /// See [`Cursor`] for more information.
+    fn array_cursor<const N: usize>(self) -> ArrayCursor<I, N>;
+
+    /// Creates a cursor over the items of the iterator, which is able to step back over all the
+    /// items it has passed.
+    ///
+    /// See [`Cursor`] for more information.
+    #[cfg(feature = "alloc")]
+    fn deque_cursor(self) -> DequeCursor<I>;
 }
 
 impl<I> DoubleEndedPeekableExt<I> for I
@@ -90,6 +173,30 @@ where
             back: MaybePeeked::Unpeeked,
         }
     }
+
+    #[inline]
+    fn array_peekable<const FRONT: usize, const BACK: usize>(
+        self,
+    ) -> ArrayPeekable<I, FRONT, BACK> {
+        MultiPeekable::new(self)
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn deque_peekable(self) -> DequePeekable<I> {
+        MultiPeekable::new(self)
+    }
+
+    #[inline]
+    fn array_cursor<const N: usize>(self) -> ArrayCursor<I, N> {
+        Cursor::new(self)
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn deque_cursor(self) -> DequeCursor<I> {
+        Cursor::new(self)
+    }
 }
 
 /// An advanced version of [`Peekable`] that works well with double-ended iterators.
@@ -104,6 +211,15 @@ pub struct DoubleEndedPeekable<I: Iterator> {
     back: MaybePeeked<<I as Iterator>::Item>,
 }
 
+/// The iterator returned by [`DoubleEndedPeekable::into_inner`].
+///
+/// It yields the item peeked from the front, then the items of the underlying iterator and
+/// finally the item peeked from the back.
+pub type IntoInner<I> = Chain<
+    Chain<option::IntoIter<<I as Iterator>::Item>, I>,
+    option::IntoIter<<I as Iterator>::Item>,
+>;
+
 impl<I: Iterator> DoubleEndedPeekable<I> {
     /// Returns a reference to the `next()` value without advancing the iterator.
     ///
@@ -161,6 +277,350 @@ impl<I: Iterator> DoubleEndedPeekable<I> {
     {
         self.next_if(|item| item == expected)
     }
+
+    /// Consumes and returns the next `N` items as an array.
+    ///
+    /// The item peeked from the front, if any, is the first one of the chunk. If the iteration
+    /// ends before `N` items are taken, the consumed items are returned as a [`PartialChunk`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use double_ended_peekable::DoubleEndedPeekableExt;
+    ///
+    /// let mut iter = [1, 2, 3, 4, 5].into_iter().double_ended_peekable();
+    ///
+    /// assert_eq!(iter.peek(), Some(&1));
+    /// assert_eq!(iter.next_chunk::<2>(), Ok([1, 2]));
+    ///
+    /// let partial = iter.next_chunk::<4>().unwrap_err();
+    /// assert_eq!(partial.collect::<Vec<_>>(), [3, 4, 5]);
+    /// ```
+    #[inline]
+    pub fn next_chunk<const N: usize>(&mut self) -> Result<[I::Item; N], PartialChunk<I::Item, N>> {
+        chunk::collect_front(|| self.next())
+    }
+
+    /// Returns a view over the front of this iterator, which can be used as a peekable iterator.
+    ///
+    /// The view shares the peeked items with this adapter, therefore an item peeked from the
+    /// view is not lost when the view is dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use double_ended_peekable::{DoubleEndedPeekableExt, FrontView};
+    ///
+    /// fn skip_spaces<I: Iterator<Item = char>>(view: &mut FrontView<'_, I>) {
+    ///     while view.next_if_eq(&' ').is_some() {}
+    /// }
+    ///
+    /// let mut iter = "  abc  ".chars().double_ended_peekable();
+    /// skip_spaces(&mut iter.front_view());
+    /// assert_eq!(iter.collect::<String>(), "abc  ");
+    /// ```
+    #[inline]
+    pub fn front_view(&mut self) -> FrontView<'_, I> {
+        FrontView::new(self)
+    }
+
+    /// Returns an iterator that consumes the items from the front as long as they satisfy
+    /// `predicate`.
+    ///
+    /// Unlike [`Iterator::take_while`], the first item not satisfying `predicate` is not lost: it
+    /// stays peeked at the front of this iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use double_ended_peekable::DoubleEndedPeekableExt;
+    ///
+    /// let mut iter = "123abc".chars().double_ended_peekable();
+    ///
+    /// let digits = iter.next_while(char::is_ascii_digit).collect::<String>();
+    /// assert_eq!(digits, "123");
+    /// assert_eq!(iter.collect::<String>(), "abc");
+    /// ```
+    #[inline]
+    pub fn next_while<P>(&mut self, predicate: P) -> NextWhile<'_, I, P>
+    where
+        P: FnMut(&I::Item) -> bool,
+    {
+        NextWhile::new(self, predicate)
+    }
+
+    /// Consumes and drops the items from the front as long as they satisfy `predicate`, returning
+    /// how many items have been skipped.
+    ///
+    /// The first item not satisfying `predicate` stays peeked at the front of this iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use double_ended_peekable::DoubleEndedPeekableExt;
+    ///
+    /// let mut iter = "   abc".chars().double_ended_peekable();
+    ///
+    /// assert_eq!(iter.skip_while_front(|c| c.is_whitespace()), 3);
+    /// assert_eq!(iter.peek(), Some(&'a'));
+    /// ```
+    #[inline]
+    pub fn skip_while_front<P>(&mut self, predicate: P) -> usize
+    where
+        P: FnMut(&I::Item) -> bool,
+    {
+        self.next_while(predicate).count()
+    }
+
+    /// Consumes and drops the items from the front as long as they satisfy `predicate`, then it
+    /// returns the adapter itself.
+    ///
+    /// This is like [`skip_while_front`], but it allows to directly iterate over the remaining
+    /// items.
+    ///
+    /// [`skip_while_front`]: Self::skip_while_front
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use double_ended_peekable::DoubleEndedPeekableExt;
+    ///
+    /// let mut iter = [0, 0, 4, 2].into_iter().double_ended_peekable();
+    ///
+    /// let remaining = iter.trim_start_while(|&x| x == 0).collect::<Vec<_>>();
+    /// assert_eq!(remaining, [4, 2]);
+    /// ```
+    #[inline]
+    pub fn trim_start_while<P>(&mut self, predicate: P) -> &mut Self
+    where
+        P: FnMut(&I::Item) -> bool,
+    {
+        self.skip_while_front(predicate);
+        self
+    }
+
+    /// Consumes and returns the next value of this iterator if a condition is true, otherwise it
+    /// returns the reason why nothing was consumed.
+    ///
+    /// This is like [`next_if`], except that it tells apart the end of the iteration from an item
+    /// not satisfying `func`. In the latter case, the rejected item is still peeked and a
+    /// reference to it is returned.
+    ///
+    /// [`next_if`]: Self::next_if
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use double_ended_peekable::{DoubleEndedPeekableExt, Rejected};
+    ///
+    /// let mut iter = ['a', '1'].into_iter().double_ended_peekable();
+    ///
+    /// assert_eq!(iter.try_next_if(char::is_ascii_alphabetic), Ok('a'));
+    /// assert_eq!(
+    ///     iter.try_next_if(char::is_ascii_alphabetic),
+    ///     Err(Rejected::Mismatch(&'1'))
+    /// );
+    /// assert_eq!(iter.next(), Some('1'));
+    /// assert_eq!(
+    ///     iter.try_next_if(char::is_ascii_alphabetic),
+    ///     Err(Rejected::Exhausted)
+    /// );
+    /// ```
+    #[inline]
+    pub fn try_next_if(
+        &mut self,
+        func: impl FnOnce(&I::Item) -> bool,
+    ) -> Result<I::Item, Rejected<'_, I::Item>> {
+        match self.next() {
+            Some(item) if func(&item) => Ok(item),
+            other => {
+                debug_assert!(self.front.is_unpeeked());
+                self.front = MaybePeeked::Peeked(other);
+                Err(self.front.peeked_value_ref().into())
+            }
+        }
+    }
+
+    /// Consumes and returns the next item if it is equal to `expected`, otherwise it returns the
+    /// reason why nothing was consumed.
+    ///
+    /// See [`try_next_if`] for more information.
+    ///
+    /// [`try_next_if`]: Self::try_next_if
+    #[inline]
+    pub fn try_next_if_eq<T>(&mut self, expected: &T) -> Result<I::Item, Rejected<'_, I::Item>>
+    where
+        T: ?Sized,
+        I::Item: PartialEq<T>,
+    {
+        self.try_next_if(|item| item == expected)
+    }
+
+    /// Consumes the next value of this iterator and applies a function `f` on it, returning the
+    /// result if it is [`Ok`].
+    ///
+    /// If `f` returns [`Err`], the item it gives back is stored again as the _front_ peeked item,
+    /// and `None` is returned. This avoids checking and converting an item in two separate steps.
+    ///
+    /// # Examples
+    ///
+    /// Parse a leading number.
+    ///
+    /// ```
+    /// use double_ended_peekable::DoubleEndedPeekableExt;
+    ///
+    /// let mut iter = "4x2".chars().double_ended_peekable();
+    ///
+    /// assert_eq!(iter.next_if_map(|c| c.to_digit(10).ok_or(c)), Some(4));
+    /// assert_eq!(iter.next_if_map(|c| c.to_digit(10).ok_or(c)), None);
+    /// // The rejected item is not lost
+    /// assert_eq!(iter.next(), Some('x'));
+    /// ```
+    #[inline]
+    pub fn next_if_map<R>(&mut self, f: impl FnOnce(I::Item) -> Result<R, I::Item>) -> Option<R> {
+        let unpeek = match self.next() {
+            Some(item) => match f(item) {
+                Ok(result) => return Some(result),
+                Err(item) => Some(item),
+            },
+            None => None,
+        };
+
+        debug_assert!(self.front.is_unpeeked());
+        self.front = MaybePeeked::Peeked(unpeek);
+        None
+    }
+
+    /// Puts an item back at the front of the iterator, so that it is returned by the following
+    /// call to `next()`.
+    ///
+    /// The adapter can only hold one item at its front: if an item has already been peeked from
+    /// the front, or pushed there, `item` is returned back as an error.
+    ///
+    /// A slot holding the end of the iteration, because it has been peeked past it, is considered
+    /// empty: the pushed item replaces the end marker, and once the item has been returned the
+    /// underlying iterator is polled again. Pushing an item is therefore the only way this adapter
+    /// returns `Some` after returning `None`, even when the underlying iterator is a
+    /// [`FusedIterator`].
+    ///
+    /// Like any other item at the front, the pushed item is also returned by `peek_back()` and
+    /// `next_back()` once all the other items have been consumed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use double_ended_peekable::DoubleEndedPeekableExt;
+    ///
+    /// let mut iter = [1, 2, 3].into_iter().double_ended_peekable();
+    ///
+    /// let item = iter.next().unwrap();
+    /// assert_eq!(iter.try_push_front(item), Ok(()));
+    /// assert_eq!(iter.peek(), Some(&1));
+    ///
+    /// // The front slot is already occupied
+    /// assert_eq!(iter.try_push_front(0), Err(0));
+    /// assert_eq!(iter.collect::<Vec<_>>(), [1, 2, 3]);
+    /// ```
+    #[inline]
+    pub fn try_push_front(&mut self, item: I::Item) -> Result<(), I::Item> {
+        match self.front {
+            MaybePeeked::Peeked(Some(_)) => Err(item),
+            MaybePeeked::Peeked(None) | MaybePeeked::Unpeeked => {
+                self.front = MaybePeeked::Peeked(Some(item));
+                Ok(())
+            }
+        }
+    }
+
+    /// Decomposes the adapter into the item peeked from the front, the underlying iterator and
+    /// the item peeked from the back.
+    ///
+    /// Each peeked item is `None` if nothing has been peeked from the respective end,
+    /// `Some(None)` if the underlying iterator has been peeked past its end and `Some(Some(item))`
+    /// if `item` has been peeked. The items can be later joined back using [`from_parts`].
+    ///
+    /// [`from_parts`]: Self::from_parts
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use double_ended_peekable::{DoubleEndedPeekable, DoubleEndedPeekableExt};
+    ///
+    /// let mut iter = [1, 2, 3, 4].into_iter().double_ended_peekable();
+    /// assert_eq!(iter.peek(), Some(&1));
+    ///
+    /// let (front, inner, back) = iter.into_parts();
+    /// assert_eq!(front, Some(Some(1)));
+    /// assert_eq!(inner.as_slice(), [2, 3, 4]);
+    /// assert_eq!(back, None);
+    ///
+    /// let iter = DoubleEndedPeekable::from_parts(front, inner, back);
+    /// assert_eq!(iter.collect::<Vec<_>>(), [1, 2, 3, 4]);
+    /// ```
+    #[inline]
+    #[allow(clippy::type_complexity)]
+    pub fn into_parts(self) -> (Option<Option<I::Item>>, I, Option<Option<I::Item>>) {
+        (self.front.into_option(), self.iter, self.back.into_option())
+    }
+
+    /// Creates the adapter from the item peeked from the front, the underlying iterator and the
+    /// item peeked from the back.
+    ///
+    /// This is the inverse of [`into_parts`], see its documentation for the meaning of the
+    /// peeked items. Keep in mind that `Some(None)` must only be used when `iter` is exhausted,
+    /// otherwise the peeked item of the opposite end could be returned before the ones of `iter`.
+    ///
+    /// [`into_parts`]: Self::into_parts
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use double_ended_peekable::DoubleEndedPeekable;
+    ///
+    /// let mut iter = DoubleEndedPeekable::from_parts(Some(Some(0)), 1..3, Some(Some(3)));
+    /// assert_eq!(iter.peek(), Some(&0));
+    /// assert_eq!(iter.peek_back(), Some(&3));
+    /// assert_eq!(iter.collect::<Vec<_>>(), [0, 1, 2, 3]);
+    /// ```
+    #[inline]
+    pub fn from_parts(
+        front: Option<Option<I::Item>>,
+        iter: I,
+        back: Option<Option<I::Item>>,
+    ) -> Self {
+        Self {
+            iter,
+            front: MaybePeeked::from_option(front),
+            back: MaybePeeked::from_option(back),
+        }
+    }
+
+    /// Returns the underlying iterator, chained with the items that have been peeked from its
+    /// ends.
+    ///
+    /// No item is lost: the returned iterator yields the same items the adapter would.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use double_ended_peekable::DoubleEndedPeekableExt;
+    ///
+    /// let mut iter = [1, 2, 3, 4].into_iter().double_ended_peekable();
+    /// assert_eq!(iter.peek(), Some(&1));
+    /// assert_eq!(iter.peek_back(), Some(&4));
+    ///
+    /// let mut inner = iter.into_inner();
+    /// assert_eq!(inner.next_back(), Some(4));
+    /// assert_eq!(inner.collect::<Vec<_>>(), [1, 2, 3]);
+    /// ```
+    #[inline]
+    pub fn into_inner(self) -> IntoInner<I> {
+        self.front
+            .into_peeked_value()
+            .into_iter()
+            .chain(self.iter)
+            .chain(self.back.into_peeked_value())
+    }
 }
 
 impl<I: DoubleEndedIterator> DoubleEndedPeekable<I> {
@@ -320,71 +780,325 @@ impl<I: DoubleEndedIterator> DoubleEndedPeekable<I> {
         self.next_back_if(|item| item == expected)
     }
 
-    /// Consumes and returns the _front_ and _back_ elements of this iterator if a condition is true.
-    ///
-    /// If `func` returns `true` given the references to the _front_ and _back_ elements of this
-    /// iterator, it consumes the elements and returns them. Otherwise, it returns `None`.
+    /// Consumes and returns the last `N` items as an array.
     ///
-    /// If there is only one element left, it returns `None`;
+    /// The items are in the same order they have in the iterator, therefore the item peeked from
+    /// the back, if any, is the last one of the chunk. If the iteration ends before `N` items are
+    /// taken, the consumed items are returned as a [`PartialChunk`], also in the same order they
+    /// have in the iterator.
     ///
     /// # Examples
-    /// Consume a pair of numbers if the first is 0 and the second is 4.
-    /// ```
-    /// use double_ended_peekable::DoubleEndedPeekableExt;
     ///
-    /// let mut iter = (0..5).double_ended_peekable();
-    /// // The first item of the iterator is 0 and the last is 4; consume it.
-    /// assert_eq!(iter.next_front_back_if(|&a, &b| a == 0 && b == 4), Some((0, 4)));
-    /// // The pair returned is now `(1, 3)`, so `consume` will return `false`.
-    /// assert_eq!(iter.next_front_back_if(|&a, &b| a == 0 && b == 4), None);
-    /// // `next_front_back_if` saves the both the _front_ and the _back_ values if the function
-    /// // returned `false`.
-    /// assert_eq!(iter.next(), Some(1));
-    /// assert_eq!(iter.next_back(), Some(3));
-    /// ```
+    /// Take a trailing checksum.
     ///
-    /// Consume any number greater than 10, in pairs.
     /// ```
     /// use double_ended_peekable::DoubleEndedPeekableExt;
     ///
-    /// let mut iter = [12, 11, 10, 9, 10, 11, 12, 13].into_iter().double_ended_peekable();
-    /// // Consume all numbers greater than 10, in pairs
-    /// while iter.next_front_back_if(|&a, &b| a > 10 && b > 10).is_some() {}
-    /// // The remaining elements
-    /// assert_eq!(iter.collect::<Vec<_>>(), [10, 9, 10, 11]);
+    /// let mut iter = [1, 2, 3, 0xab, 0xcd].into_iter().double_ended_peekable();
+    ///
+    /// assert_eq!(iter.next_back_chunk::<2>(), Ok([0xab, 0xcd]));
+    ///
+    /// let partial = iter.next_back_chunk::<4>().unwrap_err();
+    /// assert_eq!(partial.collect::<Vec<_>>(), [1, 2, 3]);
     /// ```
     #[inline]
-    pub fn next_front_back_if(
+    pub fn next_back_chunk<const N: usize>(
         &mut self,
-        func: impl FnOnce(&I::Item, &I::Item) -> bool,
-    ) -> Option<(I::Item, I::Item)> {
-        match (self.next(), self.next_back()) {
-            (Some(front), Some(back)) if func(&front, &back) => Some((front, back)),
-            (front, back) => {
-                debug_assert!(self.front.is_unpeeked());
-                debug_assert!(self.back.is_unpeeked());
-                self.front = MaybePeeked::Peeked(front);
-                self.back = MaybePeeked::Peeked(back);
-                None
-            }
-        }
+    ) -> Result<[I::Item; N], PartialChunk<I::Item, N>> {
+        chunk::collect_back(|| self.next_back())
     }
 
-    /// Consumes and returns the _front_ and _back_ elements of this iterator if they are equal to
-    /// the expected values.
+    /// Returns a view over the back of this iterator, which can be used as a peekable iterator
+    /// returning the items from the last one.
     ///
-    /// # Example
-    /// Consume any number if they are 10 and 15, respectively.
-    /// ```
-    /// use double_ended_peekable::DoubleEndedPeekableExt;
+    /// The view shares the peeked items with this adapter, therefore an item peeked from the
+    /// view is not lost when the view is dropped. This allows to run a parser only knowing how to
+    /// peek forward over the tail of the iterator.
+    ///
+    /// # Examples
     ///
-    /// let mut iter = [10, 10, 9, 15].into_iter().double_ended_peekable();
-    /// // The first and the last items of the iterator are 10 and 15; consume it.
-    /// while iter.next_front_back_if_eq(&10, &15).is_some() {}
-    /// // The remaining elements
-    /// assert_eq!(iter.collect::<Vec<_>>(), [10, 9]);
     /// ```
-    #[inline]
+    /// use double_ended_peekable::{BackView, DoubleEndedPeekableExt};
+    ///
+    /// fn skip_spaces<I: DoubleEndedIterator<Item = char>>(view: &mut BackView<'_, I>) {
+    ///     while view.next_if_eq(&' ').is_some() {}
+    /// }
+    ///
+    /// let mut iter = "  abc  ".chars().double_ended_peekable();
+    /// skip_spaces(&mut iter.back_view());
+    /// assert_eq!(iter.collect::<String>(), "  abc");
+    /// ```
+    #[inline]
+    pub fn back_view(&mut self) -> BackView<'_, I> {
+        BackView::new(self)
+    }
+
+    /// Returns an iterator that consumes the items from the back as long as they satisfy
+    /// `predicate`.
+    ///
+    /// The items are returned in the same order [`next_back`] would return them. The first item
+    /// not satisfying `predicate` is not lost: it stays peeked at the back of this iterator.
+    ///
+    /// [`next_back`]: DoubleEndedIterator::next_back
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use double_ended_peekable::DoubleEndedPeekableExt;
+    ///
+    /// let mut iter = "abc123".chars().double_ended_peekable();
+    ///
+    /// let digits = iter.next_back_while(char::is_ascii_digit).collect::<String>();
+    /// assert_eq!(digits, "321");
+    /// assert_eq!(iter.collect::<String>(), "abc");
+    /// ```
+    #[inline]
+    pub fn next_back_while<P>(&mut self, predicate: P) -> NextBackWhile<'_, I, P>
+    where
+        P: FnMut(&I::Item) -> bool,
+    {
+        NextBackWhile::new(self, predicate)
+    }
+
+    /// Consumes and drops the items from the back as long as they satisfy `predicate`, returning
+    /// how many items have been skipped.
+    ///
+    /// The first item not satisfying `predicate` stays peeked at the back of this iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use double_ended_peekable::DoubleEndedPeekableExt;
+    ///
+    /// let mut iter = "abc  ".chars().double_ended_peekable();
+    ///
+    /// assert_eq!(iter.skip_while_back(|c| c.is_whitespace()), 2);
+    /// assert_eq!(iter.peek_back(), Some(&'c'));
+    /// ```
+    #[inline]
+    pub fn skip_while_back<P>(&mut self, predicate: P) -> usize
+    where
+        P: FnMut(&I::Item) -> bool,
+    {
+        self.next_back_while(predicate).count()
+    }
+
+    /// Consumes and drops the items from the back as long as they satisfy `predicate`, then it
+    /// returns the adapter itself.
+    ///
+    /// This is like [`skip_while_back`], but it allows to directly iterate over the remaining
+    /// items.
+    ///
+    /// [`skip_while_back`]: Self::skip_while_back
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use double_ended_peekable::DoubleEndedPeekableExt;
+    ///
+    /// let mut iter = [4, 2, 0, 0].into_iter().double_ended_peekable();
+    ///
+    /// let remaining = iter.trim_end_while(|&x| x == 0).collect::<Vec<_>>();
+    /// assert_eq!(remaining, [4, 2]);
+    /// ```
+    #[inline]
+    pub fn trim_end_while<P>(&mut self, predicate: P) -> &mut Self
+    where
+        P: FnMut(&I::Item) -> bool,
+    {
+        self.skip_while_back(predicate);
+        self
+    }
+
+    /// Consumes and drops the items from both the ends as long as they satisfy `predicate`, then
+    /// it returns the adapter itself.
+    ///
+    /// The items are trimmed from the front first, then from the back. Therefore, if all the
+    /// items satisfy `predicate`, they are all consumed from the front.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use double_ended_peekable::DoubleEndedPeekableExt;
+    ///
+    /// let mut iter = "  hello world ".chars().double_ended_peekable();
+    ///
+    /// let trimmed = iter.trim_while(|c| c.is_whitespace()).collect::<String>();
+    /// assert_eq!(trimmed, "hello world");
+    /// ```
+    #[inline]
+    pub fn trim_while<P>(&mut self, mut predicate: P) -> &mut Self
+    where
+        P: FnMut(&I::Item) -> bool,
+    {
+        self.skip_while_front(&mut predicate);
+        self.skip_while_back(predicate);
+        self
+    }
+
+    /// Consumes and returns the _next back_ value of this iterator if a condition is true,
+    /// otherwise it returns the reason why nothing was consumed.
+    ///
+    /// This is like [`next_back_if`], except that it tells apart the end of the iteration from an
+    /// item not satisfying `func`. In the latter case, the rejected item is still peeked and a
+    /// reference to it is returned.
+    ///
+    /// [`next_back_if`]: Self::next_back_if
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use double_ended_peekable::{DoubleEndedPeekableExt, Rejected};
+    ///
+    /// let mut iter = ['1', 'a'].into_iter().double_ended_peekable();
+    ///
+    /// assert_eq!(iter.try_next_back_if(char::is_ascii_alphabetic), Ok('a'));
+    /// assert_eq!(
+    ///     iter.try_next_back_if(char::is_ascii_alphabetic),
+    ///     Err(Rejected::Mismatch(&'1'))
+    /// );
+    /// assert_eq!(iter.next_back(), Some('1'));
+    /// assert_eq!(
+    ///     iter.try_next_back_if(char::is_ascii_alphabetic),
+    ///     Err(Rejected::Exhausted)
+    /// );
+    /// ```
+    #[inline]
+    pub fn try_next_back_if(
+        &mut self,
+        func: impl FnOnce(&I::Item) -> bool,
+    ) -> Result<I::Item, Rejected<'_, I::Item>> {
+        match self.next_back() {
+            Some(item) if func(&item) => Ok(item),
+            other => {
+                debug_assert!(self.back.is_unpeeked());
+                self.back = MaybePeeked::Peeked(other);
+                Err(self.back.peeked_value_ref().into())
+            }
+        }
+    }
+
+    /// Consumes and returns the _next back_ item if it is equal to `expected`, otherwise it
+    /// returns the reason why nothing was consumed.
+    ///
+    /// See [`try_next_back_if`] for more information.
+    ///
+    /// [`try_next_back_if`]: Self::try_next_back_if
+    #[inline]
+    pub fn try_next_back_if_eq<T>(&mut self, expected: &T) -> Result<I::Item, Rejected<'_, I::Item>>
+    where
+        T: ?Sized,
+        I::Item: PartialEq<T>,
+    {
+        self.try_next_back_if(|item| item == expected)
+    }
+
+    /// Consumes the _next back_ value of this iterator and applies a function `f` on it,
+    /// returning the result if it is [`Ok`].
+    ///
+    /// If `f` returns [`Err`], the item it gives back is stored again as the _back_ peeked item,
+    /// and `None` is returned. This avoids checking and converting an item in two separate steps.
+    ///
+    /// # Examples
+    ///
+    /// Parse a trailing number.
+    ///
+    /// ```
+    /// use double_ended_peekable::DoubleEndedPeekableExt;
+    ///
+    /// let mut iter = "4x2".chars().double_ended_peekable();
+    ///
+    /// assert_eq!(iter.next_back_if_map(|c| c.to_digit(10).ok_or(c)), Some(2));
+    /// assert_eq!(iter.next_back_if_map(|c| c.to_digit(10).ok_or(c)), None);
+    /// // The rejected item is not lost
+    /// assert_eq!(iter.next_back(), Some('x'));
+    /// ```
+    #[inline]
+    pub fn next_back_if_map<R>(
+        &mut self,
+        f: impl FnOnce(I::Item) -> Result<R, I::Item>,
+    ) -> Option<R> {
+        let unpeek = match self.next_back() {
+            Some(item) => match f(item) {
+                Ok(result) => return Some(result),
+                Err(item) => Some(item),
+            },
+            None => None,
+        };
+
+        debug_assert!(self.back.is_unpeeked());
+        self.back = MaybePeeked::Peeked(unpeek);
+        None
+    }
+
+    /// Consumes and returns the _front_ and _back_ elements of this iterator if a condition is true.
+    ///
+    /// If `func` returns `true` given the references to the _front_ and _back_ elements of this
+    /// iterator, it consumes the elements and returns them. Otherwise, it returns `None`.
+    ///
+    /// If there is only one element left, it returns `None`; use [`try_next_front_back_if`] in
+    /// order to tell this case apart.
+    ///
+    /// [`try_next_front_back_if`]: Self::try_next_front_back_if
+    ///
+    /// # Examples
+    /// Consume a pair of numbers if the first is 0 and the second is 4.
+    /// ```
+    /// use double_ended_peekable::DoubleEndedPeekableExt;
+    ///
+    /// let mut iter = (0..5).double_ended_peekable();
+    /// // The first item of the iterator is 0 and the last is 4; consume it.
+    /// assert_eq!(iter.next_front_back_if(|&a, &b| a == 0 && b == 4), Some((0, 4)));
+    /// // The pair returned is now `(1, 3)`, so `consume` will return `false`.
+    /// assert_eq!(iter.next_front_back_if(|&a, &b| a == 0 && b == 4), None);
+    /// // `next_front_back_if` saves the both the _front_ and the _back_ values if the function
+    /// // returned `false`.
+    /// assert_eq!(iter.next(), Some(1));
+    /// assert_eq!(iter.next_back(), Some(3));
+    /// ```
+    ///
+    /// Consume any number greater than 10, in pairs.
+    /// ```
+    /// use double_ended_peekable::DoubleEndedPeekableExt;
+    ///
+    /// let mut iter = [12, 11, 10, 9, 10, 11, 12, 13].into_iter().double_ended_peekable();
+    /// // Consume all numbers greater than 10, in pairs
+    /// while iter.next_front_back_if(|&a, &b| a > 10 && b > 10).is_some() {}
+    /// // The remaining elements
+    /// assert_eq!(iter.collect::<Vec<_>>(), [10, 9, 10, 11]);
+    /// ```
+    #[inline]
+    pub fn next_front_back_if(
+        &mut self,
+        func: impl FnOnce(&I::Item, &I::Item) -> bool,
+    ) -> Option<(I::Item, I::Item)> {
+        match (self.next(), self.next_back()) {
+            (Some(front), Some(back)) if func(&front, &back) => Some((front, back)),
+            (front, back) => {
+                debug_assert!(self.front.is_unpeeked());
+                debug_assert!(self.back.is_unpeeked());
+                self.front = MaybePeeked::Peeked(front);
+                self.back = MaybePeeked::Peeked(back);
+                None
+            }
+        }
+    }
+
+    /// Consumes and returns the _front_ and _back_ elements of this iterator if they are equal to
+    /// the expected values.
+    ///
+    /// # Example
+    /// Consume any number if they are 10 and 15, respectively.
+    /// ```
+    /// use double_ended_peekable::DoubleEndedPeekableExt;
+    ///
+    /// let mut iter = [10, 10, 9, 15].into_iter().double_ended_peekable();
+    /// // The first and the last items of the iterator are 10 and 15; consume it.
+    /// while iter.next_front_back_if_eq(&10, &15).is_some() {}
+    /// // The remaining elements
+    /// assert_eq!(iter.collect::<Vec<_>>(), [10, 9]);
+    /// ```
+    #[inline]
     pub fn next_front_back_if_eq<T>(
         &mut self,
         expected_front: &T,
@@ -396,6 +1110,483 @@ impl<I: DoubleEndedIterator> DoubleEndedPeekable<I> {
     {
         self.next_front_back_if(|front, back| front == expected_front && back == expected_back)
     }
+
+    /// Repeatedly consumes the _front_ and _back_ items as long as `func` returns `true` for them,
+    /// returning how many pairs have been removed.
+    ///
+    /// This is like calling [`next_front_back_if`] until it returns `None`. The first pair not
+    /// satisfying `func` is not lost, and a single item left in the middle is never consumed.
+    ///
+    /// [`next_front_back_if`]: Self::next_front_back_if
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use double_ended_peekable::DoubleEndedPeekableExt;
+    ///
+    /// let mut iter = [1, 2, 3, 4, 3, 2, 1].into_iter().double_ended_peekable();
+    ///
+    /// assert_eq!(iter.strip_ends_if(|front, back| front == back), 3);
+    /// assert_eq!(iter.collect::<Vec<_>>(), [4]);
+    /// ```
+    #[inline]
+    pub fn strip_ends_if<F>(&mut self, mut func: F) -> usize
+    where
+        F: FnMut(&I::Item, &I::Item) -> bool,
+    {
+        let mut stripped = 0;
+        while self.next_front_back_if(&mut func).is_some() {
+            stripped += 1;
+        }
+        stripped
+    }
+
+    /// Repeatedly consumes the _front_ and _back_ items as long as they are one of the given
+    /// `(open, close)` pairs, up to `max_depth` times, returning how many pairs have been removed.
+    ///
+    /// The pairs are not required to be the same at each level. The first pair of items not
+    /// matching any of `pairs` is not lost.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use double_ended_peekable::DoubleEndedPeekableExt;
+    ///
+    /// let delimiters = [('(', ')'), ('[', ']'), ('"', '"')];
+    ///
+    /// let mut iter = "([\"text\"])".chars().double_ended_peekable();
+    /// assert_eq!(iter.strip_matching(&delimiters, usize::MAX), 3);
+    /// assert_eq!(iter.collect::<String>(), "text");
+    ///
+    /// // The depth can be limited
+    /// let mut iter = "((x))".chars().double_ended_peekable();
+    /// assert_eq!(iter.strip_matching(&delimiters, 1), 1);
+    /// assert_eq!(iter.collect::<String>(), "(x)");
+    ///
+    /// // Unbalanced delimiters are left in place
+    /// let mut iter = "(x]".chars().double_ended_peekable();
+    /// assert_eq!(iter.strip_matching(&delimiters, usize::MAX), 0);
+    /// assert_eq!(iter.collect::<String>(), "(x]");
+    /// ```
+    #[inline]
+    pub fn strip_matching<T>(&mut self, pairs: &[(T, T)], max_depth: usize) -> usize
+    where
+        I::Item: PartialEq<T>,
+    {
+        let is_pair = |front: &I::Item, back: &I::Item| {
+            pairs
+                .iter()
+                .any(|(open, close)| front == open && back == close)
+        };
+
+        let mut stripped = 0;
+        while stripped < max_depth && self.next_front_back_if(is_pair).is_some() {
+            stripped += 1;
+        }
+        stripped
+    }
+
+    /// Consumes and returns both the _front_ and the _back_ items.
+    ///
+    /// When only one item is left, it is consumed and returned as [`Ends::Single`], because the
+    /// _front_ and the _back_ are the same item.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use double_ended_peekable::{DoubleEndedPeekableExt, Ends};
+    ///
+    /// let mut iter = [1, 2, 3].into_iter().double_ended_peekable();
+    ///
+    /// assert_eq!(iter.next_front_back(), Ends::Pair(1, 3));
+    /// assert_eq!(iter.next_front_back(), Ends::Single(2));
+    /// assert_eq!(iter.next_front_back(), Ends::Empty);
+    /// ```
+    #[inline]
+    pub fn next_front_back(&mut self) -> Ends<I::Item> {
+        let Some(front) = self.next() else {
+            return Ends::Empty;
+        };
+
+        match self.next_back() {
+            Some(back) => Ends::Pair(front, back),
+            None => Ends::Single(front),
+        }
+    }
+
+    /// Consumes and returns the _front_ and _back_ items of this iterator if a condition is true,
+    /// otherwise it returns the reason why nothing was consumed.
+    ///
+    /// This is like [`next_front_back_if`], except that when nothing is consumed the peeked items
+    /// are returned as an error:
+    ///
+    /// - [`Ends::Empty`] if there are no items left;
+    /// - [`Ends::Single`] if only one item is left, in which case `func` is not called;
+    /// - [`Ends::Pair`] if `func` returned `false`.
+    ///
+    /// [`next_front_back_if`]: Self::next_front_back_if
+    ///
+    /// # Examples
+    ///
+    /// Check whether a sequence is a palindrome, consuming it.
+    ///
+    /// ```
+    /// use double_ended_peekable::{DoubleEndedPeekableExt, Ends};
+    ///
+    /// let mut iter = "racecar".chars().double_ended_peekable();
+    /// let middle = loop {
+    ///     match iter.try_next_front_back_if(|a, b| a == b) {
+    ///         Ok(_) => {}
+    ///         Err(Ends::Empty) => break None,
+    ///         Err(Ends::Single(&middle)) => break Some(middle),
+    ///         Err(Ends::Pair(a, b)) => panic!("{a} and {b} do not match"),
+    ///     }
+    /// };
+    /// assert_eq!(middle, Some('e'));
+    /// ```
+    #[inline]
+    #[allow(clippy::type_complexity)]
+    pub fn try_next_front_back_if(
+        &mut self,
+        func: impl FnOnce(&I::Item, &I::Item) -> bool,
+    ) -> Result<(I::Item, I::Item), Ends<&I::Item>> {
+        let (front, back) = match self.next_front_back() {
+            Ends::Pair(front, back) if func(&front, &back) => return Ok((front, back)),
+            Ends::Pair(front, back) => (Some(front), Some(back)),
+            Ends::Single(item) => (Some(item), None),
+            Ends::Empty => (None, None),
+        };
+
+        debug_assert!(self.front.is_unpeeked());
+        debug_assert!(self.back.is_unpeeked());
+        self.front = MaybePeeked::Peeked(front);
+        self.back = MaybePeeked::Peeked(back);
+        Err(self.peek_front_back())
+    }
+
+    /// Consumes and returns the _front_ and _back_ items of this iterator if they are equal to the
+    /// expected values, otherwise it returns the reason why nothing was consumed.
+    ///
+    /// See [`try_next_front_back_if`] for more information.
+    ///
+    /// [`try_next_front_back_if`]: Self::try_next_front_back_if
+    #[inline]
+    #[allow(clippy::type_complexity)]
+    pub fn try_next_front_back_if_eq<T>(
+        &mut self,
+        expected_front: &T,
+        expected_back: &T,
+    ) -> Result<(I::Item, I::Item), Ends<&I::Item>>
+    where
+        T: ?Sized,
+        I::Item: PartialEq<T>,
+    {
+        self.try_next_front_back_if(|front, back| front == expected_front && back == expected_back)
+    }
+
+    /// Looks at both the _front_ and the _back_ items, then consumes the ones selected by `func`.
+    ///
+    /// `func` is called with the references to the _front_ and the _back_ items, and the returned
+    /// [`Take`] decides which ends are advanced. The items that are not taken stay peeked, and
+    /// the consumed ones are returned as `(front, back)`.
+    ///
+    /// If there are no items left, `func` is not called and nothing is returned. If only one item
+    /// is left, `func` receives it as both the _front_ and the _back_ item; the item is consumed
+    /// once, and it is returned as the _back_ item only for [`Take::Back`].
+    ///
+    /// # Examples
+    ///
+    /// Merge two sorted halves, one ascending from the front and one descending from the back.
+    ///
+    /// ```
+    /// use double_ended_peekable::{DoubleEndedPeekableExt, Take};
+    ///
+    /// let mut iter = [1, 4, 6, 5, 3, 2].into_iter().double_ended_peekable();
+    /// let mut merged = Vec::new();
+    /// loop {
+    ///     let ends = iter.next_ends_with(|front, back| {
+    ///         if front <= back {
+    ///             Take::Front
+    ///         } else {
+    ///             Take::Back
+    ///         }
+    ///     });
+    ///     match ends {
+    ///         (Some(item), None) | (None, Some(item)) => merged.push(item),
+    ///         _ => break,
+    ///     }
+    /// }
+    /// assert_eq!(merged, [1, 2, 3, 4, 5, 6]);
+    /// ```
+    #[inline]
+    pub fn next_ends_with(
+        &mut self,
+        func: impl FnOnce(&I::Item, &I::Item) -> Take,
+    ) -> (Option<I::Item>, Option<I::Item>) {
+        let take = match self.peek_front_back() {
+            Ends::Empty => return (None, None),
+            Ends::Single(item) => func(item, item),
+            Ends::Pair(front, back) => func(front, back),
+        };
+
+        match take {
+            Take::Front => (self.next(), None),
+            Take::Back => (None, self.next_back()),
+            Take::Both => {
+                let front = self.next();
+                (front, self.next_back())
+            }
+            Take::None => (None, None),
+        }
+    }
+
+    /// Scans the iterator from both the ends until they meet, driven by `func`.
+    ///
+    /// At each step `func` is called with the state and the references to the _front_ and the
+    /// _back_ items, and the returned [`Take`] decides which ends are advanced, exactly like
+    /// [`next_ends_with`]. The consumed items are dropped. The scan stops when `func` returns
+    /// [`Take::None`] or when there are no items left, then the final state is returned.
+    ///
+    /// When only one item is left, `func` receives it as both the _front_ and the _back_ item,
+    /// and taking any end consumes it.
+    ///
+    /// [`next_ends_with`]: Self::next_ends_with
+    ///
+    /// # Examples
+    ///
+    /// Find the container with most water.
+    ///
+    /// ```
+    /// use double_ended_peekable::{DoubleEndedPeekableExt, Take};
+    ///
+    /// let heights: [usize; 9] = [1, 8, 6, 2, 5, 4, 8, 3, 7];
+    /// let max_area = heights
+    ///     .into_iter()
+    ///     .enumerate()
+    ///     .double_ended_peekable()
+    ///     .two_pointer(0, |max_area, &(left, left_height), &(right, right_height)| {
+    ///         *max_area = (*max_area).max((right - left) * left_height.min(right_height));
+    ///         if left_height < right_height {
+    ///             Take::Front
+    ///         } else {
+    ///             Take::Back
+    ///         }
+    ///     });
+    /// assert_eq!(max_area, 49);
+    /// ```
+    ///
+    /// Stop as soon as a condition is met.
+    ///
+    /// ```
+    /// use double_ended_peekable::{DoubleEndedPeekableExt, Take};
+    ///
+    /// let mut iter = "abcxba".chars().double_ended_peekable();
+    /// let matching = iter.two_pointer(0, |matching, front, back| {
+    ///     if front == back {
+    ///         *matching += 1;
+    ///         Take::Both
+    ///     } else {
+    ///         Take::None
+    ///     }
+    /// });
+    /// assert_eq!(matching, 2);
+    /// assert_eq!(iter.collect::<String>(), "cx");
+    /// ```
+    #[inline]
+    pub fn two_pointer<S, F>(&mut self, init: S, mut func: F) -> S
+    where
+        F: FnMut(&mut S, &I::Item, &I::Item) -> Take,
+    {
+        let mut state = init;
+        loop {
+            let take = match self.peek_front_back() {
+                Ends::Empty => break,
+                Ends::Single(item) => func(&mut state, item, item),
+                Ends::Pair(front, back) => func(&mut state, front, back),
+            };
+
+            match take {
+                Take::Front => {
+                    self.next();
+                }
+                Take::Back => {
+                    self.next_back();
+                }
+                Take::Both => {
+                    self.next();
+                    self.next_back();
+                }
+                Take::None => break,
+            }
+        }
+
+        state
+    }
+
+    /// Returns references to both the _front_ and the _back_ items without advancing the
+    /// iterator.
+    ///
+    /// Unlike calling [`peek`] and then [`peek_back`], the references can be held at the same
+    /// time. When only one item is left, it is returned as [`Ends::Single`], because the _front_
+    /// and the _back_ are the same item.
+    ///
+    /// [`peek`]: Self::peek
+    /// [`peek_back`]: Self::peek_back
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use double_ended_peekable::{DoubleEndedPeekableExt, Ends};
+    ///
+    /// let mut iter = [1, 2, 3].into_iter().double_ended_peekable();
+    ///
+    /// assert_eq!(iter.peek_front_back(), Ends::Pair(&1, &3));
+    /// assert_eq!(iter.next_back(), Some(3));
+    /// assert_eq!(iter.next_back(), Some(2));
+    ///
+    /// // The only item left is both the front and the back one
+    /// assert_eq!(iter.peek_front_back(), Ends::Single(&1));
+    /// assert_eq!(iter.next(), Some(1));
+    /// assert_eq!(iter.peek_front_back(), Ends::Empty);
+    /// ```
+    #[inline]
+    pub fn peek_front_back(&mut self) -> Ends<&I::Item> {
+        self.fill_ends();
+        match (self.front.peeked_value_ref(), self.back.peeked_value_ref()) {
+            (Some(front), Some(back)) => Ends::Pair(front, back),
+            (Some(item), None) | (None, Some(item)) => Ends::Single(item),
+            (None, None) => Ends::Empty,
+        }
+    }
+
+    /// Returns mutable references to both the _front_ and the _back_ items without advancing the
+    /// iterator.
+    ///
+    /// See [`peek_front_back`] for more information.
+    ///
+    /// [`peek_front_back`]: Self::peek_front_back
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use double_ended_peekable::{DoubleEndedPeekableExt, Ends};
+    ///
+    /// let mut iter = [1, 2, 3].into_iter().double_ended_peekable();
+    ///
+    /// if let Ends::Pair(front, back) = iter.peek_front_back_mut() {
+    ///     std::mem::swap(front, back);
+    /// }
+    /// assert_eq!(iter.collect::<Vec<_>>(), [3, 2, 1]);
+    /// ```
+    #[inline]
+    pub fn peek_front_back_mut(&mut self) -> Ends<&mut I::Item> {
+        self.fill_ends();
+        match (self.front.peeked_value_mut(), self.back.peeked_value_mut()) {
+            (Some(front), Some(back)) => Ends::Pair(front, back),
+            (Some(item), None) | (None, Some(item)) => Ends::Single(item),
+            (None, None) => Ends::Empty,
+        }
+    }
+
+    /// Peeks both the _front_ and the _back_ items, unless they are already peeked.
+    #[inline]
+    fn fill_ends(&mut self) {
+        self.front.get_peeked_or_insert_with(|| self.iter.next());
+        self.back
+            .get_peeked_or_insert_with(|| self.iter.next_back());
+    }
+
+    /// Puts an item back at the back of the iterator, so that it is returned by the following
+    /// call to `next_back()`.
+    ///
+    /// The adapter can only hold one item at its back: if an item has already been peeked from
+    /// the back, or pushed there, `item` is returned back as an error.
+    ///
+    /// A slot holding the end of the iteration, because it has been peeked past it, is considered
+    /// empty: the pushed item replaces the end marker, and once the item has been returned the
+    /// underlying iterator is polled again. Pushing an item is therefore the only way this adapter
+    /// returns `Some` after returning `None`, even when the underlying iterator is a
+    /// [`FusedIterator`].
+    ///
+    /// Like any other item at the back, the pushed item is also returned by `peek()` and `next()`
+    /// once all the other items have been consumed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use double_ended_peekable::DoubleEndedPeekableExt;
+    ///
+    /// let mut iter = [1, 2, 3].into_iter().double_ended_peekable();
+    ///
+    /// let item = iter.next_back().unwrap();
+    /// assert_eq!(iter.try_push_back(item), Ok(()));
+    /// assert_eq!(iter.peek_back(), Some(&3));
+    ///
+    /// // The back slot is already occupied
+    /// assert_eq!(iter.try_push_back(4), Err(4));
+    /// assert_eq!(iter.collect::<Vec<_>>(), [1, 2, 3]);
+    /// ```
+    #[inline]
+    pub fn try_push_back(&mut self, item: I::Item) -> Result<(), I::Item> {
+        match self.back {
+            MaybePeeked::Peeked(Some(_)) => Err(item),
+            MaybePeeked::Peeked(None) | MaybePeeked::Unpeeked => {
+                self.back = MaybePeeked::Peeked(Some(item));
+                Ok(())
+            }
+        }
+    }
+
+    /// Inverts the direction of the iteration, keeping the peeked items.
+    ///
+    /// The _front_ of the returned adapter is the _back_ of this one, and vice versa. Therefore,
+    /// `peek()` of the returned adapter returns what `peek_back()` would have returned before,
+    /// and no peeked item is lost. Unlike [`rev`], the returned adapter is still a
+    /// [`DoubleEndedPeekable`], and it can be turned back using [`unreversed`].
+    ///
+    /// [`rev`]: Iterator::rev
+    /// [`unreversed`]: DoubleEndedPeekable::unreversed
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use double_ended_peekable::DoubleEndedPeekableExt;
+    ///
+    /// let mut iter = [1, 2, 3, 4].into_iter().double_ended_peekable();
+    /// assert_eq!(iter.peek(), Some(&1));
+    /// assert_eq!(iter.peek_back(), Some(&4));
+    ///
+    /// let mut reversed = iter.reversed();
+    /// assert_eq!(reversed.peek(), Some(&4));
+    /// assert_eq!(reversed.next_back(), Some(1));
+    /// assert_eq!(reversed.next(), Some(4));
+    ///
+    /// let iter = reversed.unreversed();
+    /// assert_eq!(iter.collect::<Vec<_>>(), [2, 3]);
+    /// ```
+    #[inline]
+    pub fn reversed(self) -> DoubleEndedPeekable<Reversed<I>> {
+        DoubleEndedPeekable {
+            iter: Reversed::new(self.iter),
+            front: self.back,
+            back: self.front,
+        }
+    }
+}
+
+impl<I: DoubleEndedIterator> DoubleEndedPeekable<Reversed<I>> {
+    /// Restores the original direction of an adapter created by [`reversed`], keeping the peeked
+    /// items.
+    ///
+    /// [`reversed`]: DoubleEndedPeekable::reversed
+    #[inline]
+    pub fn unreversed(self) -> DoubleEndedPeekable<I> {
+        DoubleEndedPeekable {
+            iter: self.iter.into_inner(),
+            front: self.back,
+            back: self.front,
+        }
+    }
 }
 
 impl<I> Iterator for DoubleEndedPeekable<I>
@@ -419,13 +1610,79 @@ where
     #[inline]
     fn size_hint(&self) -> (usize, Option<usize>) {
         let (lower, upper) = self.iter.size_hint();
-        let additional = match (&self.front, &self.back) {
-            (MaybePeeked::Peeked(_), MaybePeeked::Peeked(_)) => 2,
-            (MaybePeeked::Peeked(_), _) | (_, MaybePeeked::Peeked(_)) => 1,
-            (MaybePeeked::Unpeeked, MaybePeeked::Unpeeked) => 0,
+        let additional = usize::from(self.front.peeked_value_ref().is_some())
+            + usize::from(self.back.peeked_value_ref().is_some());
+
+        (
+            lower.saturating_add(additional),
+            upper.and_then(|upper| upper.checked_add(additional)),
+        )
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        let peeked = usize::from(self.front.peeked_value_ref().is_some())
+            + usize::from(self.back.peeked_value_ref().is_some());
+
+        match self.front {
+            // The underlying iterator has been already exhausted from the front
+            MaybePeeked::Peeked(None) => peeked,
+            MaybePeeked::Peeked(Some(_)) | MaybePeeked::Unpeeked => self.iter.count() + peeked,
+        }
+    }
+
+    #[inline]
+    fn last(self) -> Option<Self::Item> {
+        match (self.front, self.back) {
+            (_, MaybePeeked::Peeked(out @ Some(_))) => out,
+            (MaybePeeked::Peeked(None), _) => None,
+            (front, MaybePeeked::Peeked(None)) => front.into_peeked_value(),
+            (front, MaybePeeked::Unpeeked) => self.iter.last().or(front.into_peeked_value()),
+        }
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let n = match self.front.take() {
+            MaybePeeked::Peeked(out @ Some(_)) => match n.checked_sub(1) {
+                Some(n) => n,
+                None => return out,
+            },
+            MaybePeeked::Peeked(None) => {
+                return self.back.take().into_peeked_value().filter(|_| n == 0);
+            }
+            MaybePeeked::Unpeeked => n,
+        };
+
+        // If the underlying iterator has less than `n` items, the one peeked from the back is
+        // skipped as well.
+        if n > 0 && self.iter.nth(n - 1).is_none() {
+            self.back.take();
+            return None;
+        }
+
+        match self.iter.next() {
+            item @ Some(_) => item,
+            None => self.back.take().into_peeked_value(),
+        }
+    }
+
+    #[inline]
+    fn fold<B, F>(self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, Self::Item) -> B,
+    {
+        let acc = match self.front {
+            MaybePeeked::Peeked(Some(item)) => self.iter.fold(f(init, item), &mut f),
+            // The underlying iterator has been already exhausted from the front
+            MaybePeeked::Peeked(None) => init,
+            MaybePeeked::Unpeeked => self.iter.fold(init, &mut f),
         };
 
-        (lower + additional, upper.map(|upper| upper + additional))
+        match self.back {
+            MaybePeeked::Peeked(Some(item)) => f(acc, item),
+            MaybePeeked::Peeked(None) | MaybePeeked::Unpeeked => acc,
+        }
     }
 }
 
@@ -444,6 +1701,78 @@ where
             },
         }
     }
+
+    #[inline]
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        let n = match self.back.take() {
+            MaybePeeked::Peeked(out @ Some(_)) => match n.checked_sub(1) {
+                Some(n) => n,
+                None => return out,
+            },
+            MaybePeeked::Peeked(None) => {
+                return self.front.take().into_peeked_value().filter(|_| n == 0);
+            }
+            MaybePeeked::Unpeeked => n,
+        };
+
+        // If the underlying iterator has less than `n` items, the one peeked from the front is
+        // skipped as well.
+        if n > 0 && self.iter.nth_back(n - 1).is_none() {
+            self.front.take();
+            return None;
+        }
+
+        match self.iter.next_back() {
+            item @ Some(_) => item,
+            None => self.front.take().into_peeked_value(),
+        }
+    }
+
+    #[inline]
+    fn rfold<B, F>(self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, Self::Item) -> B,
+    {
+        let acc = match self.back {
+            MaybePeeked::Peeked(Some(item)) => self.iter.rfold(f(init, item), &mut f),
+            // The underlying iterator has been already exhausted from the back
+            MaybePeeked::Peeked(None) => init,
+            MaybePeeked::Unpeeked => self.iter.rfold(init, &mut f),
+        };
+
+        match self.front {
+            MaybePeeked::Peeked(Some(item)) => f(acc, item),
+            MaybePeeked::Peeked(None) | MaybePeeked::Unpeeked => acc,
+        }
+    }
+}
+
+impl<I> ExactSizeIterator for DoubleEndedPeekable<I> where I: ExactSizeIterator {}
+
+impl<I> FusedIterator for DoubleEndedPeekable<I> where I: FusedIterator {}
+
+impl<I: ExactSizeIterator> DoubleEndedPeekable<I> {
+    /// Returns `true` if the iterator is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use double_ended_peekable::DoubleEndedPeekableExt;
+    ///
+    /// let mut iter = [1, 2].into_iter().double_ended_peekable();
+    /// assert!(!iter.is_empty());
+    ///
+    /// assert_eq!(iter.peek_back(), Some(&2));
+    /// assert_eq!(iter.next(), Some(1));
+    /// assert!(!iter.is_empty());
+    ///
+    /// assert_eq!(iter.next(), Some(2));
+    /// assert!(iter.is_empty());
+    /// ```
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
 }
 
 impl<I> Debug for DoubleEndedPeekable<I>
@@ -506,6 +1835,37 @@ where
     }
 }
 
+/// Wraps a [`Peekable`] without losing the item it has already peeked, if any.
+///
+/// The standard library does not allow to take the underlying iterator nor the peeked item out
+/// of a [`Peekable`] without advancing it. Therefore, the [`Peekable`] is kept as the underlying
+/// iterator, and the item it has peeked is returned first from the front. If all the other items
+/// are consumed from the back, it is also returned last from the back, exactly like
+/// [`Peekable`] does when it is used as a [`DoubleEndedIterator`].
+///
+/// # Examples
+///
+/// ```
+/// use double_ended_peekable::DoubleEndedPeekable;
+///
+/// let mut peekable = [1, 2, 3].into_iter().peekable();
+/// assert_eq!(peekable.peek(), Some(&1));
+///
+/// let mut iter = DoubleEndedPeekable::from(peekable);
+/// assert_eq!(iter.peek(), Some(&1));
+/// assert_eq!(iter.peek_back(), Some(&3));
+/// assert_eq!(iter.collect::<Vec<_>>(), [1, 2, 3]);
+/// ```
+impl<I> From<Peekable<I>> for DoubleEndedPeekable<Peekable<I>>
+where
+    I: Iterator,
+{
+    #[inline]
+    fn from(peekable: Peekable<I>) -> Self {
+        peekable.double_ended_peekable()
+    }
+}
+
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(kani, derive(kani::Arbitrary))]
 enum MaybePeeked<T> {
@@ -545,6 +1905,14 @@ impl<T> MaybePeeked<T> {
         }
     }
 
+    #[cfg(test)]
+    fn map<U>(self, f: impl FnOnce(T) -> U) -> MaybePeeked<U> {
+        match self {
+            MaybePeeked::Unpeeked => MaybePeeked::Unpeeked,
+            MaybePeeked::Peeked(peeked) => MaybePeeked::Peeked(peeked.map(f)),
+        }
+    }
+
     const fn is_unpeeked(&self) -> bool {
         matches!(self, MaybePeeked::Unpeeked)
     }
@@ -559,6 +1927,20 @@ impl<T> MaybePeeked<T> {
             MaybePeeked::Peeked(Some(peeked)) => Some(peeked),
         }
     }
+
+    fn into_option(self) -> Option<Option<T>> {
+        match self {
+            MaybePeeked::Unpeeked => None,
+            MaybePeeked::Peeked(peeked) => Some(peeked),
+        }
+    }
+
+    fn from_option(peeked: Option<Option<T>>) -> Self {
+        match peeked {
+            None => MaybePeeked::Unpeeked,
+            Some(peeked) => MaybePeeked::Peeked(peeked),
+        }
+    }
 }
 
 #[cfg(kani)]
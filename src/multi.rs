@@ -0,0 +1,1426 @@
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "alloc")]
+use alloc::collections::{vec_deque, VecDeque};
+use core::{
+    fmt::{self, Debug},
+    hash::{Hash, Hasher},
+    iter::FusedIterator,
+};
+#[cfg(feature = "alloc")]
+use core::{
+    iter::{Chain, Rev},
+    ops::{Bound, RangeBounds},
+};
+
+use crate::{
+    buffer::{ArrayBuffer, Buffer, CapacityError},
+    chunk,
+};
+
+/// A version of [`DoubleEndedPeekable`] able to look ahead multiple items from both the ends of
+/// the iterator.
+///
+/// While [`DoubleEndedPeekable`] can only store one peeked item for each end, this adapter
+/// buffers the peeked items inside a couple of [`Buffer`]s, `F` for the front and `B` for the
+/// back. When the underlying iterator is exhausted, the items buffered at one end are reachable
+/// from the other one, exactly like [`DoubleEndedPeekable::peek`] falls back to the item peeked
+/// from the back.
+///
+/// Once the underlying iterator returns `None`, it is never polled again.
+///
+/// You probably want to use one of its specializations:
+///
+/// - [`ArrayPeekable`], which uses fixed-size buffers stored inline and does not allocate;
+/// - `DequePeekable`, which uses unbounded buffers and requires the `alloc` feature.
+///
+/// [`DoubleEndedPeekable`]: crate::DoubleEndedPeekable
+/// [`DoubleEndedPeekable::peek`]: crate::DoubleEndedPeekable::peek
+pub struct MultiPeekable<I: Iterator, F, B> {
+    iter: I,
+    front: F,
+    back: B,
+    exhausted: bool,
+}
+
+/// A [`MultiPeekable`] that can look ahead up to `FRONT` items from the front and `BACK` items
+/// from the back, without allocating.
+///
+/// Peeking beyond the capacity of a buffer returns `None`, use [`try_peek_nth`] and
+/// [`try_peek_back_nth`] in order to distinguish this case from the end of the iteration.
+///
+/// `ArrayPeekable<I, 1, 1>` behaves like [`DoubleEndedPeekable<I>`], except that once the
+/// underlying iterator returns `None` it is never polled again, as if it were fused.
+///
+/// This type is created by the [`array_peekable`] method on [`DoubleEndedPeekableExt`].
+///
+/// [`try_peek_nth`]: MultiPeekable::try_peek_nth
+/// [`try_peek_back_nth`]: MultiPeekable::try_peek_back_nth
+/// [`DoubleEndedPeekable<I>`]: crate::DoubleEndedPeekable
+/// [`DoubleEndedPeekableExt`]: crate::DoubleEndedPeekableExt
+/// [`array_peekable`]: crate::DoubleEndedPeekableExt::array_peekable
+pub type ArrayPeekable<I, const FRONT: usize, const BACK: usize> = MultiPeekable<
+    I,
+    ArrayBuffer<<I as Iterator>::Item, FRONT>,
+    ArrayBuffer<<I as Iterator>::Item, BACK>,
+>;
+
+/// A [`MultiPeekable`] that can look ahead an arbitrary number of items from both the ends.
+///
+/// Items are buffered in a couple of [`VecDeque`]s, therefore peeking never fails because of the
+/// capacity of the buffers.
+///
+/// This type is created by the [`deque_peekable`] method on [`DoubleEndedPeekableExt`], and it
+/// is only available with the `alloc` feature.
+///
+/// [`DoubleEndedPeekableExt`]: crate::DoubleEndedPeekableExt
+/// [`deque_peekable`]: crate::DoubleEndedPeekableExt::deque_peekable
+#[cfg(feature = "alloc")]
+pub type DequePeekable<I> =
+    MultiPeekable<I, VecDeque<<I as Iterator>::Item>, VecDeque<<I as Iterator>::Item>>;
+
+impl<I, F, B> MultiPeekable<I, F, B>
+where
+    I: Iterator,
+    F: Buffer<I::Item> + Default,
+    B: Buffer<I::Item> + Default,
+{
+    #[inline]
+    pub(crate) fn new(iter: I) -> Self {
+        Self {
+            iter,
+            front: F::default(),
+            back: B::default(),
+            exhausted: false,
+        }
+    }
+}
+
+impl<I, F, B> MultiPeekable<I, F, B>
+where
+    I: Iterator,
+    F: Buffer<I::Item>,
+    B: Buffer<I::Item>,
+{
+    /// Returns a reference to the `next()` value without advancing the iterator.
+    ///
+    /// Equivalent to `peek_nth(0)`.
+    #[inline]
+    pub fn peek(&mut self) -> Option<&I::Item> {
+        self.peek_nth(0)
+    }
+
+    /// Returns a mutable reference to the `next()` value without advancing the iterator.
+    ///
+    /// Equivalent to `peek_nth_mut(0)`.
+    #[inline]
+    pub fn peek_mut(&mut self) -> Option<&mut I::Item> {
+        self.peek_nth_mut(0)
+    }
+
+    /// Consumes and returns the next value of this iterator if a condition is true.
+    ///
+    /// See [`DoubleEndedPeekable::next_if`] for more information.
+    ///
+    /// [`DoubleEndedPeekable::next_if`]: crate::DoubleEndedPeekable::next_if
+    #[inline]
+    pub fn next_if(&mut self, func: impl FnOnce(&I::Item) -> bool) -> Option<I::Item> {
+        match self.peek() {
+            Some(item) if func(item) => self.next(),
+            _ => None,
+        }
+    }
+
+    /// Consumes and returns the next item if it is equal to `expected`.
+    ///
+    /// See [`DoubleEndedPeekable::next_if_eq`] for more information.
+    ///
+    /// [`DoubleEndedPeekable::next_if_eq`]: crate::DoubleEndedPeekable::next_if_eq
+    #[inline]
+    pub fn next_if_eq<T>(&mut self, expected: &T) -> Option<I::Item>
+    where
+        T: ?Sized,
+        I::Item: PartialEq<T>,
+    {
+        self.next_if(|item| item == expected)
+    }
+
+    /// Returns a reference to the `n`th value from the front without advancing the iterator.
+    ///
+    /// `peek_nth(0)` returns the same item `next()` would return, `peek_nth(1)` the one after it,
+    /// and so on. All the items up to the `n`th are buffered, therefore they are not lost.
+    ///
+    /// If the front buffer is not able to store enough items to reach the `n`th one, `None` is
+    /// returned. Use [`try_peek_nth`] to handle this case explicitly.
+    ///
+    /// [`try_peek_nth`]: Self::try_peek_nth
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use double_ended_peekable::DoubleEndedPeekableExt;
+    ///
+    /// let mut iter = [1, 2, 3, 4].into_iter().array_peekable::<3, 3>();
+    ///
+    /// assert_eq!(iter.peek_nth(2), Some(&3));
+    /// assert_eq!(iter.peek_back_nth(2), Some(&2));
+    ///
+    /// // Peeking does not advance the iterator
+    /// assert_eq!(iter.next(), Some(1));
+    /// assert_eq!(iter.peek_nth(2), Some(&4));
+    /// assert_eq!(iter.peek_nth(3), None);
+    /// ```
+    #[inline]
+    pub fn peek_nth(&mut self, n: usize) -> Option<&I::Item> {
+        self.try_peek_nth(n).ok().flatten()
+    }
+
+    /// Returns a mutable reference to the `n`th value from the front without advancing the
+    /// iterator.
+    ///
+    /// See [`peek_nth`] for more information.
+    ///
+    /// [`peek_nth`]: Self::peek_nth
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use double_ended_peekable::DoubleEndedPeekableExt;
+    ///
+    /// let mut iter = [1, 2, 3].into_iter().array_peekable::<2, 0>();
+    ///
+    /// if let Some(p) = iter.peek_nth_mut(1) {
+    ///     *p = 5;
+    /// }
+    ///
+    /// assert_eq!(iter.collect::<Vec<_>>(), vec![1, 5, 3]);
+    /// ```
+    #[inline]
+    pub fn peek_nth_mut(&mut self, n: usize) -> Option<&mut I::Item> {
+        self.try_peek_nth_mut(n).ok().flatten()
+    }
+
+    /// Returns a reference to the `n`th value from the front without advancing the iterator, or
+    /// an error if the front buffer is full before reaching it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use double_ended_peekable::{CapacityError, DoubleEndedPeekableExt};
+    ///
+    /// let mut iter = [1, 2, 3].into_iter().array_peekable::<2, 1>();
+    ///
+    /// assert_eq!(iter.try_peek_nth(1), Ok(Some(&2)));
+    /// assert_eq!(iter.try_peek_nth(2), Err(CapacityError));
+    ///
+    /// // Once the underlying iterator is exhausted, the back buffer can be reached
+    /// assert_eq!(iter.next(), Some(1));
+    /// assert_eq!(iter.peek_back(), Some(&3));
+    /// assert_eq!(iter.try_peek_nth(1), Ok(Some(&3)));
+    /// assert_eq!(iter.try_peek_nth(2), Ok(None));
+    /// ```
+    #[inline]
+    pub fn try_peek_nth(&mut self, n: usize) -> Result<Option<&I::Item>, CapacityError> {
+        self.fill_front(n)?;
+        let front_len = self.front.len();
+        Ok(self
+            .front
+            .get(n)
+            .or_else(|| self.back.get(inner_index(self.back.len(), n - front_len)?)))
+    }
+
+    /// Returns a mutable reference to the `n`th value from the front without advancing the
+    /// iterator, or an error if the front buffer is full before reaching it.
+    ///
+    /// See [`try_peek_nth`] for more information.
+    ///
+    /// [`try_peek_nth`]: Self::try_peek_nth
+    #[inline]
+    pub fn try_peek_nth_mut(&mut self, n: usize) -> Result<Option<&mut I::Item>, CapacityError> {
+        self.fill_front(n)?;
+        let front_len = self.front.len();
+        let back_len = self.back.len();
+        Ok(self
+            .front
+            .get_mut(n)
+            .or_else(|| self.back.get_mut(inner_index(back_len, n - front_len)?)))
+    }
+
+    /// Puts an item back at the front of the iterator, so that it is returned by the following
+    /// call to `next()`.
+    ///
+    /// If the front buffer is full, `item` is returned back as an error. The items that have
+    /// already been peeked are kept, and they follow the pushed one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use double_ended_peekable::DoubleEndedPeekableExt;
+    ///
+    /// let mut iter = [1, 2, 3].into_iter().array_peekable::<2, 0>();
+    ///
+    /// let item = iter.next().unwrap();
+    /// assert_eq!(iter.peek(), Some(&2));
+    /// assert_eq!(iter.try_push_front(item), Ok(()));
+    /// assert_eq!(iter.peek_nth(1), Some(&2));
+    ///
+    /// // The front buffer is full
+    /// assert_eq!(iter.try_push_front(0), Err(0));
+    /// assert_eq!(iter.collect::<Vec<_>>(), [1, 2, 3]);
+    /// ```
+    #[inline]
+    pub fn try_push_front(&mut self, item: I::Item) -> Result<(), I::Item> {
+        if self.front.is_full() {
+            return Err(item);
+        }
+
+        self.front.push_outer(item);
+        Ok(())
+    }
+
+    /// Returns `true` if the next items of the iterator are equal to `prefix`, without advancing
+    /// the iterator, or an error if `prefix` is longer than the capacity of the front buffer. A
+    /// prefix that does not fit is never mistaken for a mismatch, and the capacity is checked
+    /// before inspecting any item, therefore the error does not depend on the items of the
+    /// iterator.
+    ///
+    /// All the inspected items are buffered, therefore they are not lost. With the unbounded
+    /// buffers of `DequePeekable`, which cannot fail, `starts_with` can be used instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use double_ended_peekable::{CapacityError, DoubleEndedPeekableExt};
+    ///
+    /// let mut iter = [1, 2, 3].into_iter().array_peekable::<2, 0>();
+    ///
+    /// assert_eq!(iter.try_starts_with(&[1, 2]), Ok(true));
+    /// assert_eq!(iter.try_starts_with(&[1, 2, 3]), Err(CapacityError));
+    /// assert_eq!(iter.try_starts_with(&[9, 2, 3]), Err(CapacityError));
+    /// ```
+    pub fn try_starts_with<T>(&mut self, prefix: &[T]) -> Result<bool, CapacityError>
+    where
+        I::Item: PartialEq<T>,
+    {
+        check_capacity(&self.front, prefix.len())?;
+
+        for (index, expected) in prefix.iter().enumerate() {
+            if !self
+                .try_peek_nth(index)?
+                .is_some_and(|item| item == expected)
+            {
+                return Ok(false);
+            }
+        }
+
+        Ok(true)
+    }
+
+    /// Consumes the next items of the iterator if they are equal to `prefix`, returning whether
+    /// they have been consumed, or an error if `prefix` is longer than the capacity of the front
+    /// buffer, which is never mistaken for a mismatch.
+    ///
+    /// On mismatch, the iterator is not advanced and all the inspected items are buffered. See
+    /// [`try_starts_with`] for more information.
+    ///
+    /// [`try_starts_with`]: Self::try_starts_with
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use double_ended_peekable::{CapacityError, DoubleEndedPeekableExt};
+    ///
+    /// let mut iter = b"POST /".iter().copied().array_peekable::<4, 0>();
+    ///
+    /// assert_eq!(iter.try_strip_prefix(b"POST "), Err(CapacityError));
+    /// assert_eq!(iter.try_strip_prefix(b"POST"), Ok(true));
+    /// assert_eq!(iter.next(), Some(b' '));
+    /// ```
+    pub fn try_strip_prefix<T>(&mut self, prefix: &[T]) -> Result<bool, CapacityError>
+    where
+        I::Item: PartialEq<T>,
+    {
+        let matches = self.try_starts_with(prefix)?;
+        if matches {
+            for _ in prefix {
+                self.next();
+            }
+        }
+        Ok(matches)
+    }
+
+    /// Consumes and returns the next `N` items as an array if `func` returns `true` for them.
+    ///
+    /// `func` receives the next `N` items in the same order they have in the iterator. If there
+    /// are not enough items or `func` returns `false`, the iterator is not advanced and all the
+    /// inspected items are buffered. If `N` is greater than the capacity of the front buffer,
+    /// `None` is returned without polling the iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use double_ended_peekable::DoubleEndedPeekableExt;
+    ///
+    /// let mut iter = [0xca, 0xfe, 0x01].into_iter().array_peekable::<2, 0>();
+    ///
+    /// assert_eq!(iter.next_chunk_if(|&[a, b]| a == 0xff && b == 0xfe), None);
+    /// assert_eq!(iter.next_chunk_if(|&[a, _]| a == 0xca), Some([0xca, 0xfe]));
+    /// assert_eq!(iter.next_chunk_if(|_: &[_; 2]| true), None);
+    /// assert_eq!(iter.next(), Some(0x01));
+    /// ```
+    pub fn next_chunk_if<const N: usize>(
+        &mut self,
+        func: impl FnOnce(&[I::Item; N]) -> bool,
+    ) -> Option<[I::Item; N]> {
+        if !self.fill_front_chunk(N) {
+            return None;
+        }
+
+        let chunk = &self.front.make_contiguous()[..N];
+        if !<&[I::Item; N]>::try_from(chunk).is_ok_and(func) {
+            return None;
+        }
+
+        chunk::collect_front(|| self.front.pop_outer()).ok()
+    }
+
+    /// Consumes and returns the next `N` items as an array if they are equal to `expected`.
+    ///
+    /// See [`next_chunk_if`] for more information.
+    ///
+    /// [`next_chunk_if`]: Self::next_chunk_if
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use double_ended_peekable::DoubleEndedPeekableExt;
+    ///
+    /// let mut iter = b"\x7fELF\x02".iter().copied().array_peekable::<4, 0>();
+    ///
+    /// assert_eq!(iter.next_chunk_if_eq(b"\x7fELF"), Some(*b"\x7fELF"));
+    /// assert_eq!(iter.next(), Some(2));
+    /// ```
+    #[inline]
+    pub fn next_chunk_if_eq<T, const N: usize>(&mut self, expected: &[T; N]) -> Option<[I::Item; N]>
+    where
+        I::Item: PartialEq<T>,
+    {
+        self.next_chunk_if(|chunk| {
+            chunk
+                .iter()
+                .zip(expected)
+                .all(|(item, expected)| item == expected)
+        })
+    }
+
+    /// Makes sure that the front buffer contains `n` items, moving them from the back buffer if
+    /// the underlying iterator is exhausted. Returns `false` if there are not enough items, or
+    /// without polling the iterator if the front buffer is not able to store them.
+    fn fill_front_chunk(&mut self, n: usize) -> bool {
+        if check_capacity(&self.front, n).is_err() {
+            return false;
+        }
+
+        if n == 0 {
+            return true;
+        }
+
+        if self.fill_front(n - 1).is_err() {
+            return false;
+        }
+
+        while self.front.len() < n {
+            let Some(item) = self.back.pop_inner() else {
+                return false;
+            };
+
+            self.front.push_inner(item);
+        }
+
+        true
+    }
+
+    /// Makes sure that the front buffer contains the `n`th item, unless the underlying iterator
+    /// is exhausted.
+    fn fill_front(&mut self, n: usize) -> Result<(), CapacityError> {
+        while self.front.len() <= n && !self.exhausted {
+            if self.front.is_full() {
+                return Err(CapacityError);
+            }
+
+            match self.iter.next() {
+                Some(item) => self.front.push_inner(item),
+                None => self.exhausted = true,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<I, F, B> MultiPeekable<I, F, B>
+where
+    I: DoubleEndedIterator,
+    F: Buffer<I::Item>,
+    B: Buffer<I::Item>,
+{
+    /// Returns a reference to the `next_back()` value without advancing the _back_ of the
+    /// iterator.
+    ///
+    /// Equivalent to `peek_back_nth(0)`.
+    #[inline]
+    pub fn peek_back(&mut self) -> Option<&I::Item> {
+        self.peek_back_nth(0)
+    }
+
+    /// Returns a mutable reference to the `next_back()` value without advancing the _back_ of the
+    /// iterator.
+    ///
+    /// Equivalent to `peek_back_nth_mut(0)`.
+    #[inline]
+    pub fn peek_back_mut(&mut self) -> Option<&mut I::Item> {
+        self.peek_back_nth_mut(0)
+    }
+
+    /// Consumes and returns the _next back_ value of this iterator if a condition is true.
+    ///
+    /// See [`DoubleEndedPeekable::next_back_if`] for more information.
+    ///
+    /// [`DoubleEndedPeekable::next_back_if`]: crate::DoubleEndedPeekable::next_back_if
+    #[inline]
+    pub fn next_back_if(&mut self, func: impl FnOnce(&I::Item) -> bool) -> Option<I::Item> {
+        match self.peek_back() {
+            Some(item) if func(item) => self.next_back(),
+            _ => None,
+        }
+    }
+
+    /// Consumes and returns the _next back_ item if it is equal to `expected`.
+    ///
+    /// See [`DoubleEndedPeekable::next_back_if_eq`] for more information.
+    ///
+    /// [`DoubleEndedPeekable::next_back_if_eq`]: crate::DoubleEndedPeekable::next_back_if_eq
+    #[inline]
+    pub fn next_back_if_eq<T>(&mut self, expected: &T) -> Option<I::Item>
+    where
+        T: ?Sized,
+        I::Item: PartialEq<T>,
+    {
+        self.next_back_if(|item| item == expected)
+    }
+
+    /// Consumes and returns the _front_ and _back_ elements of this iterator if a condition is
+    /// true.
+    ///
+    /// If there are less than two items left, `None` is returned and `func` is not called. See
+    /// [`DoubleEndedPeekable::next_front_back_if`] for more information.
+    ///
+    /// [`DoubleEndedPeekable::next_front_back_if`]: crate::DoubleEndedPeekable::next_front_back_if
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use double_ended_peekable::DoubleEndedPeekableExt;
+    ///
+    /// let mut iter = [12, 11, 10, 9, 10, 11, 12, 13].into_iter().array_peekable::<1, 1>();
+    /// while iter.next_front_back_if(|&a, &b| a > 10 && b > 10).is_some() {}
+    /// assert_eq!(iter.collect::<Vec<_>>(), [10, 9, 10, 11]);
+    /// ```
+    pub fn next_front_back_if(
+        &mut self,
+        func: impl FnOnce(&I::Item, &I::Item) -> bool,
+    ) -> Option<(I::Item, I::Item)> {
+        self.peek()?;
+        self.peek_back()?;
+
+        // Once both the ends have been peeked, an empty buffer means that the underlying iterator
+        // is exhausted and all the remaining items are in the other buffer.
+        let (front, back) = match (self.front.len(), self.back.len()) {
+            (0, len) if len >= 2 => (self.back.get(len - 1)?, self.back.get(0)?),
+            (len, 0) if len >= 2 => (self.front.get(0)?, self.front.get(len - 1)?),
+            (0, _) | (_, 0) => return None,
+            _ => (self.front.get(0)?, self.back.get(0)?),
+        };
+
+        if !func(front, back) {
+            return None;
+        }
+
+        Some((self.next()?, self.next_back()?))
+    }
+
+    /// Consumes and returns the _front_ and _back_ elements of this iterator if they are equal to
+    /// the expected values.
+    ///
+    /// See [`next_front_back_if`] for more information.
+    ///
+    /// [`next_front_back_if`]: Self::next_front_back_if
+    #[inline]
+    pub fn next_front_back_if_eq<T>(
+        &mut self,
+        expected_front: &T,
+        expected_back: &T,
+    ) -> Option<(I::Item, I::Item)>
+    where
+        T: ?Sized,
+        I::Item: PartialEq<T>,
+    {
+        self.next_front_back_if(|front, back| front == expected_front && back == expected_back)
+    }
+
+    /// Returns a reference to the `n`th value from the back without advancing the iterator.
+    ///
+    /// `peek_back_nth(0)` returns the same item `next_back()` would return, `peek_back_nth(1)` the
+    /// one before it, and so on. All the items up to the `n`th from the back are buffered,
+    /// therefore they are not lost.
+    ///
+    /// If the back buffer is not able to store enough items to reach the `n`th one, `None` is
+    /// returned. Use [`try_peek_back_nth`] to handle this case explicitly.
+    ///
+    /// [`try_peek_back_nth`]: Self::try_peek_back_nth
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use double_ended_peekable::DoubleEndedPeekableExt;
+    ///
+    /// let mut iter = [1, 2, 3, 4].into_iter().array_peekable::<2, 2>();
+    ///
+    /// assert_eq!(iter.peek_back_nth(1), Some(&3));
+    /// assert_eq!(iter.next_back(), Some(4));
+    ///
+    /// // The front and the back buffers meet in the middle
+    /// assert_eq!(iter.peek_nth(1), Some(&2));
+    /// assert_eq!(iter.peek_back_nth(2), Some(&1));
+    /// assert_eq!(iter.peek_back_nth(3), None);
+    /// ```
+    #[inline]
+    pub fn peek_back_nth(&mut self, n: usize) -> Option<&I::Item> {
+        self.try_peek_back_nth(n).ok().flatten()
+    }
+
+    /// Returns a mutable reference to the `n`th value from the back without advancing the
+    /// iterator.
+    ///
+    /// See [`peek_back_nth`] for more information.
+    ///
+    /// [`peek_back_nth`]: Self::peek_back_nth
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use double_ended_peekable::DoubleEndedPeekableExt;
+    ///
+    /// let mut iter = [1, 2, 3].into_iter().array_peekable::<0, 3>();
+    ///
+    /// if let Some(p) = iter.peek_back_nth_mut(2) {
+    ///     *p = 5;
+    /// }
+    ///
+    /// assert_eq!(iter.collect::<Vec<_>>(), vec![5, 2, 3]);
+    /// ```
+    #[inline]
+    pub fn peek_back_nth_mut(&mut self, n: usize) -> Option<&mut I::Item> {
+        self.try_peek_back_nth_mut(n).ok().flatten()
+    }
+
+    /// Returns a reference to the `n`th value from the back without advancing the iterator, or
+    /// an error if the back buffer is full before reaching it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use double_ended_peekable::{CapacityError, DoubleEndedPeekableExt};
+    ///
+    /// let mut iter = [1, 2, 3, 4].into_iter().array_peekable::<1, 2>();
+    ///
+    /// assert_eq!(iter.try_peek_back_nth(1), Ok(Some(&3)));
+    /// assert_eq!(iter.try_peek_back_nth(2), Err(CapacityError));
+    /// ```
+    #[inline]
+    pub fn try_peek_back_nth(&mut self, n: usize) -> Result<Option<&I::Item>, CapacityError> {
+        self.fill_back(n)?;
+        let back_len = self.back.len();
+        Ok(self
+            .back
+            .get(n)
+            .or_else(|| self.front.get(inner_index(self.front.len(), n - back_len)?)))
+    }
+
+    /// Returns a mutable reference to the `n`th value from the back without advancing the
+    /// iterator, or an error if the back buffer is full before reaching it.
+    ///
+    /// See [`try_peek_back_nth`] for more information.
+    ///
+    /// [`try_peek_back_nth`]: Self::try_peek_back_nth
+    #[inline]
+    pub fn try_peek_back_nth_mut(
+        &mut self,
+        n: usize,
+    ) -> Result<Option<&mut I::Item>, CapacityError> {
+        self.fill_back(n)?;
+        let front_len = self.front.len();
+        let back_len = self.back.len();
+        Ok(self
+            .back
+            .get_mut(n)
+            .or_else(|| self.front.get_mut(inner_index(front_len, n - back_len)?)))
+    }
+
+    /// Puts an item back at the back of the iterator, so that it is returned by the following
+    /// call to `next_back()`.
+    ///
+    /// If the back buffer is full, `item` is returned back as an error. The items that have
+    /// already been peeked are kept, and they follow the pushed one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use double_ended_peekable::DoubleEndedPeekableExt;
+    ///
+    /// let mut iter = [1, 2, 3].into_iter().array_peekable::<0, 2>();
+    ///
+    /// let item = iter.next_back().unwrap();
+    /// assert_eq!(iter.peek_back(), Some(&2));
+    /// assert_eq!(iter.try_push_back(item), Ok(()));
+    /// assert_eq!(iter.peek_back_nth(1), Some(&2));
+    ///
+    /// // The back buffer is full
+    /// assert_eq!(iter.try_push_back(4), Err(4));
+    /// assert_eq!(iter.collect::<Vec<_>>(), [1, 2, 3]);
+    /// ```
+    #[inline]
+    pub fn try_push_back(&mut self, item: I::Item) -> Result<(), I::Item> {
+        if self.back.is_full() {
+            return Err(item);
+        }
+
+        self.back.push_outer(item);
+        Ok(())
+    }
+
+    /// Returns `true` if the last items of the iterator are equal to `suffix`, without advancing
+    /// the iterator, or an error if `suffix` is longer than the capacity of the back buffer. A
+    /// suffix that does not fit is never mistaken for a mismatch, and the capacity is checked
+    /// before inspecting any item, therefore the error does not depend on the items of the
+    /// iterator.
+    ///
+    /// `suffix` is in the same order the items are returned by `next()`. All the inspected items
+    /// are buffered, therefore they are not lost. With the unbounded buffers of `DequePeekable`,
+    /// which cannot fail, `ends_with` can be used instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use double_ended_peekable::{CapacityError, DoubleEndedPeekableExt};
+    ///
+    /// let mut iter = [1, 2, 3].into_iter().array_peekable::<0, 2>();
+    ///
+    /// assert_eq!(iter.try_ends_with(&[2, 3]), Ok(true));
+    /// assert_eq!(iter.try_ends_with(&[1, 2, 3]), Err(CapacityError));
+    /// assert_eq!(iter.try_ends_with(&[1, 2, 9]), Err(CapacityError));
+    /// ```
+    pub fn try_ends_with<T>(&mut self, suffix: &[T]) -> Result<bool, CapacityError>
+    where
+        I::Item: PartialEq<T>,
+    {
+        check_capacity(&self.back, suffix.len())?;
+
+        for (index, expected) in suffix.iter().rev().enumerate() {
+            if !self
+                .try_peek_back_nth(index)?
+                .is_some_and(|item| item == expected)
+            {
+                return Ok(false);
+            }
+        }
+
+        Ok(true)
+    }
+
+    /// Consumes the last items of the iterator if they are equal to `suffix`, returning whether
+    /// they have been consumed, or an error if `suffix` is longer than the capacity of the back
+    /// buffer, which is never mistaken for a mismatch.
+    ///
+    /// On mismatch, the iterator is not advanced and all the inspected items are buffered. See
+    /// [`try_ends_with`] for more information.
+    ///
+    /// [`try_ends_with`]: Self::try_ends_with
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use double_ended_peekable::{CapacityError, DoubleEndedPeekableExt};
+    ///
+    /// let mut iter = b"frame\r\n".iter().copied().array_peekable::<0, 2>();
+    ///
+    /// assert_eq!(iter.try_strip_suffix(b"e\r\n"), Err(CapacityError));
+    /// assert_eq!(iter.try_strip_suffix(b"\r\n"), Ok(true));
+    /// assert_eq!(iter.next_back(), Some(b'e'));
+    /// ```
+    pub fn try_strip_suffix<T>(&mut self, suffix: &[T]) -> Result<bool, CapacityError>
+    where
+        I::Item: PartialEq<T>,
+    {
+        let matches = self.try_ends_with(suffix)?;
+        if matches {
+            for _ in suffix {
+                self.next_back();
+            }
+        }
+        Ok(matches)
+    }
+
+    /// Consumes and returns the last `N` items as an array if `func` returns `true` for them.
+    ///
+    /// `func` receives the last `N` items in the same order they have in the iterator, and the
+    /// returned array has the same order. If there are not enough items or `func` returns
+    /// `false`, the iterator is not advanced and all the inspected items are buffered. If `N` is
+    /// greater than the capacity of the back buffer, `None` is returned without polling the
+    /// iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use double_ended_peekable::DoubleEndedPeekableExt;
+    ///
+    /// let mut iter = b"line\r\n".iter().copied().array_peekable::<0, 2>();
+    ///
+    /// assert_eq!(iter.next_back_chunk_if(|&[_, b]| b == b'\r'), None);
+    /// assert_eq!(iter.next_back_chunk_if(|&[a, _]| a == b'\r'), Some(*b"\r\n"));
+    /// assert_eq!(iter.next_back(), Some(b'e'));
+    /// ```
+    pub fn next_back_chunk_if<const N: usize>(
+        &mut self,
+        func: impl FnOnce(&[I::Item; N]) -> bool,
+    ) -> Option<[I::Item; N]> {
+        if !self.fill_back_chunk(N) {
+            return None;
+        }
+
+        // The back buffer stores the items in reverse order: they are reversed in place in order
+        // to be inspected, then the guard restores them, even if `func` panics.
+        let chunk = &mut self.back.make_contiguous()[..N];
+        chunk.reverse();
+        let chunk = ReverseOnDrop(chunk);
+        let accepted = <&[I::Item; N]>::try_from(&*chunk.0).is_ok_and(func);
+        drop(chunk);
+
+        if !accepted {
+            return None;
+        }
+
+        chunk::collect_back(|| self.back.pop_outer()).ok()
+    }
+
+    /// Consumes and returns the last `N` items as an array if they are equal to `expected`.
+    ///
+    /// See [`next_back_chunk_if`] for more information.
+    ///
+    /// [`next_back_chunk_if`]: Self::next_back_chunk_if
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use double_ended_peekable::DoubleEndedPeekableExt;
+    ///
+    /// let mut iter = b"line\r\n".iter().copied().array_peekable::<0, 2>();
+    ///
+    /// assert_eq!(iter.next_back_chunk_if_eq(b"\r\n"), Some(*b"\r\n"));
+    /// assert_eq!(iter.next_back(), Some(b'e'));
+    /// ```
+    #[inline]
+    pub fn next_back_chunk_if_eq<T, const N: usize>(
+        &mut self,
+        expected: &[T; N],
+    ) -> Option<[I::Item; N]>
+    where
+        I::Item: PartialEq<T>,
+    {
+        self.next_back_chunk_if(|chunk| {
+            chunk
+                .iter()
+                .zip(expected)
+                .all(|(item, expected)| item == expected)
+        })
+    }
+
+    /// Makes sure that the back buffer contains `n` items, moving them from the front buffer if
+    /// the underlying iterator is exhausted. Returns `false` if there are not enough items, or
+    /// without polling the iterator if the back buffer is not able to store them.
+    fn fill_back_chunk(&mut self, n: usize) -> bool {
+        if check_capacity(&self.back, n).is_err() {
+            return false;
+        }
+
+        if n == 0 {
+            return true;
+        }
+
+        if self.fill_back(n - 1).is_err() {
+            return false;
+        }
+
+        while self.back.len() < n {
+            let Some(item) = self.front.pop_inner() else {
+                return false;
+            };
+
+            self.back.push_inner(item);
+        }
+
+        true
+    }
+
+    /// Makes sure that the back buffer contains the `n`th item, unless the underlying iterator
+    /// is exhausted.
+    fn fill_back(&mut self, n: usize) -> Result<(), CapacityError> {
+        while self.back.len() <= n && !self.exhausted {
+            if self.back.is_full() {
+                return Err(CapacityError);
+            }
+
+            match self.iter.next_back() {
+                Some(item) => self.back.push_inner(item),
+                None => self.exhausted = true,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I: Iterator> DequePeekable<I> {
+    /// Puts an item back at the front of the iterator, so that it is returned by the following
+    /// call to `next()`.
+    ///
+    /// Unlike [`try_push_front`], this never fails because the front buffer is unbounded.
+    ///
+    /// [`try_push_front`]: MultiPeekable::try_push_front
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use double_ended_peekable::DoubleEndedPeekableExt;
+    ///
+    /// let mut iter = [3, 4].into_iter().deque_peekable();
+    ///
+    /// iter.push_front(2);
+    /// iter.push_front(1);
+    /// assert_eq!(iter.collect::<Vec<_>>(), [1, 2, 3, 4]);
+    /// ```
+    #[inline]
+    pub fn push_front(&mut self, item: I::Item) {
+        self.front.push_front(item);
+    }
+
+    /// Returns `true` if the next items of the iterator are equal to `prefix`, without advancing
+    /// the iterator.
+    ///
+    /// This is only provided for the unbounded buffers of `DequePeekable`, which are able to
+    /// store any prefix: with bounded buffers, [`try_starts_with`] reports a prefix longer than
+    /// the front buffer instead of mistaking it for a mismatch. All the inspected items are
+    /// buffered, therefore they are not lost.
+    ///
+    /// [`try_starts_with`]: MultiPeekable::try_starts_with
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use double_ended_peekable::DoubleEndedPeekableExt;
+    ///
+    /// let mut iter = [1, 2, 3, 4].into_iter().deque_peekable();
+    ///
+    /// assert!(iter.starts_with(&[1, 2]));
+    /// assert!(!iter.starts_with(&[1, 3]));
+    /// assert!(!iter.starts_with(&[1, 2, 3, 4, 5]));
+    /// assert_eq!(iter.next(), Some(1));
+    /// ```
+    #[inline]
+    pub fn starts_with<T>(&mut self, prefix: &[T]) -> bool
+    where
+        I::Item: PartialEq<T>,
+    {
+        // `VecDeque` buffers are never full, matching cannot fail.
+        matches!(self.try_starts_with(prefix), Ok(true))
+    }
+
+    /// Consumes the next items of the iterator if they are equal to `prefix`, returning whether
+    /// they have been consumed.
+    ///
+    /// This is only provided for the unbounded buffers of `DequePeekable`, see [`starts_with`] for
+    /// more information. On mismatch, the iterator is not advanced and all the inspected items are
+    /// buffered.
+    ///
+    /// [`starts_with`]: Self::starts_with
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use double_ended_peekable::DoubleEndedPeekableExt;
+    ///
+    /// let mut iter = b"GET /".iter().copied().deque_peekable();
+    ///
+    /// assert!(!iter.strip_prefix(b"POST"));
+    /// assert!(iter.strip_prefix(b"GET "));
+    /// assert_eq!(iter.next(), Some(b'/'));
+    /// ```
+    #[inline]
+    pub fn strip_prefix<T>(&mut self, prefix: &[T]) -> bool
+    where
+        I::Item: PartialEq<T>,
+    {
+        // `VecDeque` buffers are never full, matching cannot fail.
+        matches!(self.try_strip_prefix(prefix), Ok(true))
+    }
+
+    /// Returns an iterator over the references to the items in the given range of positions,
+    /// counting from the front, without advancing the iterator.
+    ///
+    /// All the items up to the end of the range are buffered, therefore an unbounded range
+    /// buffers all the remaining items of the underlying iterator. Positions beyond the end of
+    /// the iteration are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use double_ended_peekable::DoubleEndedPeekableExt;
+    ///
+    /// let mut iter = (1..=5).deque_peekable();
+    ///
+    /// assert!(iter.peek_range(1..3).eq(&[2, 3]));
+    /// assert!(iter.peek_range(3..).eq(&[4, 5]));
+    /// assert!(iter.peek_range(4..42).eq(&[5]));
+    ///
+    /// // Peeking does not advance the iterator
+    /// assert_eq!(iter.next(), Some(1));
+    /// assert!(iter.peek_range(..=1).eq(&[2, 3]));
+    /// ```
+    pub fn peek_range<R>(&mut self, range: R) -> PeekRange<'_, I::Item>
+    where
+        R: RangeBounds<usize>,
+    {
+        let (start, end) = range_bounds(&range);
+        if end != Some(0) {
+            // `VecDeque` buffers are never full, filling them cannot fail.
+            let _ = self.fill_front(end.map_or(usize::MAX, |end| end - 1));
+        }
+        PeekRange::new(&self.front, &self.back, start, end)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I: DoubleEndedIterator> DequePeekable<I> {
+    /// Puts an item back at the back of the iterator, so that it is returned by the following
+    /// call to `next_back()`.
+    ///
+    /// Unlike [`try_push_back`], this never fails because the back buffer is unbounded.
+    ///
+    /// [`try_push_back`]: MultiPeekable::try_push_back
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use double_ended_peekable::DoubleEndedPeekableExt;
+    ///
+    /// let mut iter = [1, 2].into_iter().deque_peekable();
+    ///
+    /// iter.push_back(3);
+    /// iter.push_back(4);
+    /// assert_eq!(iter.collect::<Vec<_>>(), [1, 2, 3, 4]);
+    /// ```
+    #[inline]
+    pub fn push_back(&mut self, item: I::Item) {
+        self.back.push_front(item);
+    }
+
+    /// Returns `true` if the last items of the iterator are equal to `suffix`, without advancing
+    /// the iterator.
+    ///
+    /// This is only provided for the unbounded buffers of `DequePeekable`, which are able to
+    /// store any suffix: with bounded buffers, [`try_ends_with`] reports a suffix longer than the
+    /// back buffer instead of mistaking it for a mismatch. `suffix` is in the same order the items
+    /// are returned by `next()`, and all the inspected items are buffered.
+    ///
+    /// [`try_ends_with`]: MultiPeekable::try_ends_with
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use double_ended_peekable::DoubleEndedPeekableExt;
+    ///
+    /// let mut iter = [1, 2, 3, 4].into_iter().deque_peekable();
+    ///
+    /// assert!(iter.ends_with(&[3, 4]));
+    /// assert!(!iter.ends_with(&[2, 4]));
+    /// assert!(!iter.ends_with(&[0, 1, 2, 3, 4]));
+    /// assert_eq!(iter.next_back(), Some(4));
+    /// ```
+    #[inline]
+    pub fn ends_with<T>(&mut self, suffix: &[T]) -> bool
+    where
+        I::Item: PartialEq<T>,
+    {
+        // `VecDeque` buffers are never full, matching cannot fail.
+        matches!(self.try_ends_with(suffix), Ok(true))
+    }
+
+    /// Consumes the last items of the iterator if they are equal to `suffix`, returning whether
+    /// they have been consumed.
+    ///
+    /// This is only provided for the unbounded buffers of `DequePeekable`, see [`ends_with`] for
+    /// more information. On mismatch, the iterator is not advanced and all the inspected items are
+    /// buffered.
+    ///
+    /// [`ends_with`]: Self::ends_with
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use double_ended_peekable::DoubleEndedPeekableExt;
+    ///
+    /// let mut iter = b"frame\r\n".iter().copied().deque_peekable();
+    ///
+    /// assert!(!iter.strip_suffix(b"\n\r"));
+    /// assert!(iter.strip_suffix(b"\r\n"));
+    /// assert_eq!(iter.next_back(), Some(b'e'));
+    /// ```
+    #[inline]
+    pub fn strip_suffix<T>(&mut self, suffix: &[T]) -> bool
+    where
+        I::Item: PartialEq<T>,
+    {
+        // `VecDeque` buffers are never full, matching cannot fail.
+        matches!(self.try_strip_suffix(suffix), Ok(true))
+    }
+
+    /// Returns an iterator over the references to the items in the given range of positions,
+    /// counting from the back, without advancing the iterator.
+    ///
+    /// The items are returned in the same order [`next_back`] would return them. All the items up
+    /// to the end of the range are buffered, therefore an unbounded range buffers all the
+    /// remaining items of the underlying iterator. Positions beyond the end of the iteration are
+    /// ignored.
+    ///
+    /// [`next_back`]: DoubleEndedIterator::next_back
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use double_ended_peekable::DoubleEndedPeekableExt;
+    ///
+    /// let mut iter = (1..=5).deque_peekable();
+    ///
+    /// assert!(iter.peek_back_range(1..3).eq(&[4, 3]));
+    /// assert!(iter.peek_back_range(3..).eq(&[2, 1]));
+    ///
+    /// // The front and the back buffers meet in the middle
+    /// assert_eq!(iter.next_back(), Some(5));
+    /// assert_eq!(iter.peek_nth(1), Some(&2));
+    /// assert!(iter.peek_back_range(..).eq(&[4, 3, 2, 1]));
+    /// ```
+    pub fn peek_back_range<R>(&mut self, range: R) -> PeekRange<'_, I::Item>
+    where
+        R: RangeBounds<usize>,
+    {
+        let (start, end) = range_bounds(&range);
+        if end != Some(0) {
+            // `VecDeque` buffers are never full, filling them cannot fail.
+            let _ = self.fill_back(end.map_or(usize::MAX, |end| end - 1));
+        }
+        PeekRange::new(&self.back, &self.front, start, end)
+    }
+}
+
+impl<I, F, B> Iterator for MultiPeekable<I, F, B>
+where
+    I: Iterator,
+    F: Buffer<I::Item>,
+    B: Buffer<I::Item>,
+{
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if let item @ Some(_) = self.front.pop_outer() {
+            return item;
+        }
+
+        if !self.exhausted {
+            match self.iter.next() {
+                item @ Some(_) => return item,
+                None => self.exhausted = true,
+            }
+        }
+
+        self.back.pop_inner()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = if self.exhausted {
+            (0, Some(0))
+        } else {
+            self.iter.size_hint()
+        };
+        let additional = self.front.len() + self.back.len();
+
+        (
+            lower.saturating_add(additional),
+            upper.and_then(|upper| upper.checked_add(additional)),
+        )
+    }
+}
+
+impl<I, F, B> DoubleEndedIterator for MultiPeekable<I, F, B>
+where
+    I: DoubleEndedIterator,
+    F: Buffer<I::Item>,
+    B: Buffer<I::Item>,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if let item @ Some(_) = self.back.pop_outer() {
+            return item;
+        }
+
+        if !self.exhausted {
+            match self.iter.next_back() {
+                item @ Some(_) => return item,
+                None => self.exhausted = true,
+            }
+        }
+
+        self.front.pop_inner()
+    }
+}
+
+impl<I, F, B> ExactSizeIterator for MultiPeekable<I, F, B>
+where
+    I: ExactSizeIterator,
+    F: Buffer<I::Item>,
+    B: Buffer<I::Item>,
+{
+}
+
+// The underlying iterator is never polled again after returning `None`, therefore it does not
+// need to be fused.
+impl<I, F, B> FusedIterator for MultiPeekable<I, F, B>
+where
+    I: Iterator,
+    F: Buffer<I::Item>,
+    B: Buffer<I::Item>,
+{
+}
+
+impl<I, F, B> MultiPeekable<I, F, B>
+where
+    I: ExactSizeIterator,
+    F: Buffer<I::Item>,
+    B: Buffer<I::Item>,
+{
+    /// Returns `true` if the iterator is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use double_ended_peekable::DoubleEndedPeekableExt;
+    ///
+    /// let mut iter = [1, 2].into_iter().array_peekable::<2, 2>();
+    /// assert_eq!(iter.peek_nth(1), Some(&2));
+    /// assert!(!iter.is_empty());
+    ///
+    /// assert_eq!(iter.next(), Some(1));
+    /// assert_eq!(iter.next(), Some(2));
+    /// assert!(iter.is_empty());
+    /// ```
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<I, F, B> Debug for MultiPeekable<I, F, B>
+where
+    I: Iterator + Debug,
+    F: Debug,
+    B: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MultiPeekable")
+            .field("iter", &self.iter)
+            .field("front", &self.front)
+            .field("back", &self.back)
+            .field("exhausted", &self.exhausted)
+            .finish()
+    }
+}
+
+impl<I, F, B> Clone for MultiPeekable<I, F, B>
+where
+    I: Iterator + Clone,
+    F: Clone,
+    B: Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            iter: self.iter.clone(),
+            front: self.front.clone(),
+            back: self.back.clone(),
+            exhausted: self.exhausted,
+        }
+    }
+}
+
+impl<I, F, B> PartialEq for MultiPeekable<I, F, B>
+where
+    I: Iterator + PartialEq,
+    F: PartialEq,
+    B: PartialEq,
+{
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.iter == other.iter
+            && self.front == other.front
+            && self.back == other.back
+            && self.exhausted == other.exhausted
+    }
+}
+
+impl<I, F, B> Eq for MultiPeekable<I, F, B>
+where
+    I: Iterator + Eq,
+    F: Eq,
+    B: Eq,
+{
+}
+
+impl<I, F, B> Hash for MultiPeekable<I, F, B>
+where
+    I: Iterator + Hash,
+    F: Hash,
+    B: Hash,
+{
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.iter.hash(state);
+        self.front.hash(state);
+        self.back.hash(state);
+        self.exhausted.hash(state);
+    }
+}
+
+/// An iterator over a range of items peeked from a [`DequePeekable`].
+///
+/// This `struct` is created by the [`peek_range`] and [`peek_back_range`] methods on
+/// [`DequePeekable`], and it is only available with the `alloc` feature.
+///
+/// [`peek_range`]: MultiPeekable::peek_range
+/// [`peek_back_range`]: MultiPeekable::peek_back_range
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone)]
+pub struct PeekRange<'a, T> {
+    inner: Chain<vec_deque::Iter<'a, T>, Rev<vec_deque::Iter<'a, T>>>,
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, T> PeekRange<'a, T> {
+    /// Creates the iterator over the items at positions `start..end` counting from the outer end
+    /// of `near`, continuing on `far` from its inner end.
+    fn new(near: &'a VecDeque<T>, far: &'a VecDeque<T>, start: usize, end: Option<usize>) -> Self {
+        let near_end = end.map_or(near.len(), |end| end.min(near.len()));
+        let near_start = start.min(near_end);
+
+        let far_end = end.map_or(far.len(), |end| {
+            end.saturating_sub(near.len()).min(far.len())
+        });
+        let far_start = start.saturating_sub(near.len()).min(far_end);
+
+        Self {
+            inner: near
+                .range(near_start..near_end)
+                .chain(far.range(far.len() - far_end..far.len() - far_start).rev()),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, T> Iterator for PeekRange<'a, T> {
+    type Item = &'a T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> DoubleEndedIterator for PeekRange<'_, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> ExactSizeIterator for PeekRange<'_, T> {}
+
+#[cfg(feature = "alloc")]
+impl<T> FusedIterator for PeekRange<'_, T> {}
+
+/// Converts a range of positions into an inclusive start and an optional exclusive end.
+#[cfg(feature = "alloc")]
+fn range_bounds(range: &impl RangeBounds<usize>) -> (usize, Option<usize>) {
+    let start = match range.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start.saturating_add(1),
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&end) => end.checked_add(1),
+        Bound::Excluded(&end) => Some(end),
+        Bound::Unbounded => None,
+    };
+
+    (start, end)
+}
+
+/// Returns the index of the `n`th element of a buffer counting from its inner end, if any.
+///
+/// Front and back buffers store the items starting from their respective ends of the iterator,
+/// therefore the items of one buffer are reached from the other one in reverse order.
+#[inline]
+const fn inner_index(len: usize, n: usize) -> Option<usize> {
+    match len.checked_sub(n) {
+        Some(remaining) => remaining.checked_sub(1),
+        None => None,
+    }
+}
+
+/// Returns an error if `buffer` is not able to store `len` items.
+#[inline]
+fn check_capacity<T>(buffer: &impl Buffer<T>, len: usize) -> Result<(), CapacityError> {
+    if buffer.capacity().is_some_and(|capacity| len > capacity) {
+        Err(CapacityError)
+    } else {
+        Ok(())
+    }
+}
+
+/// Reverses the items of a slice when dropped.
+struct ReverseOnDrop<'a, T>(&'a mut [T]);
+
+impl<T> Drop for ReverseOnDrop<'_, T> {
+    #[inline]
+    fn drop(&mut self) {
+        self.0.reverse();
+    }
+}
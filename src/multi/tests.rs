@@ -0,0 +1,671 @@
+use std::{
+    collections::hash_map::DefaultHasher,
+    panic::{self, AssertUnwindSafe},
+};
+
+use crate::{buffer::sealed::Storage, DoubleEndedPeekable, DoubleEndedPeekableExt};
+
+use super::*;
+
+fn items<T: Clone>(buffer: &impl Storage<T>) -> Vec<T> {
+    (0..buffer.len())
+        .map(|index| buffer.get(index).unwrap().clone())
+        .collect()
+}
+
+#[test]
+fn iterator() {
+    let mut iter = [0, 1, 2].into_iter().array_peekable::<4, 4>();
+
+    assert_eq!(iter.next(), Some(0));
+    assert_eq!(iter.next(), Some(1));
+    assert_eq!(iter.next(), Some(2));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.front.len(), 0);
+    assert_eq!(iter.back.len(), 0);
+    assert!(iter.exhausted);
+}
+
+#[test]
+fn double_ended_iterator() {
+    let mut iter = [0, 1, 2].into_iter().array_peekable::<4, 4>();
+
+    assert_eq!(iter.next_back(), Some(2));
+    assert_eq!(iter.next_back(), Some(1));
+    assert_eq!(iter.next_back(), Some(0));
+    assert_eq!(iter.next_back(), None);
+    assert_eq!(iter.front.len(), 0);
+    assert_eq!(iter.back.len(), 0);
+    assert!(iter.exhausted);
+}
+
+#[test]
+fn peek_nth() {
+    let mut iter = [0, 1, 2, 3].into_iter().array_peekable::<4, 4>();
+
+    assert_eq!(iter.peek_nth(1), Some(&1));
+    assert_eq!(items(&iter.front), [0, 1]);
+    assert_eq!(iter.back.len(), 0);
+
+    assert_eq!(iter.peek_nth(0), Some(&0));
+    assert_eq!(items(&iter.front), [0, 1]);
+
+    assert_eq!(iter.next(), Some(0));
+    assert_eq!(items(&iter.front), [1]);
+
+    assert_eq!(iter.peek_nth(2), Some(&3));
+    assert_eq!(items(&iter.front), [1, 2, 3]);
+    assert!(!iter.exhausted);
+
+    assert_eq!(iter.peek_nth(3), None);
+    assert!(iter.exhausted);
+
+    assert_eq!(iter.collect::<Vec<_>>(), [1, 2, 3]);
+}
+
+#[test]
+fn peek_nth_mut() {
+    let mut iter = [0, 1, 2].into_iter().array_peekable::<4, 4>();
+
+    *iter.peek_nth_mut(2).unwrap() = 5;
+    assert_eq!(iter.peek_back_nth_mut(0), Some(&mut 5));
+    *iter.peek_back_nth_mut(1).unwrap() = 4;
+    assert_eq!(iter.peek_nth_mut(42), None);
+
+    assert_eq!(iter.collect::<Vec<_>>(), [0, 4, 5]);
+}
+
+#[test]
+fn peek_back_nth() {
+    let mut iter = [0, 1, 2, 3].into_iter().array_peekable::<4, 4>();
+
+    assert_eq!(iter.peek_back_nth(1), Some(&2));
+    assert_eq!(iter.front.len(), 0);
+    assert_eq!(items(&iter.back), [3, 2]);
+
+    assert_eq!(iter.next_back(), Some(3));
+    assert_eq!(items(&iter.back), [2]);
+
+    assert_eq!(iter.peek_back_nth(2), Some(&0));
+    assert_eq!(items(&iter.back), [2, 1, 0]);
+    assert!(!iter.exhausted);
+
+    assert_eq!(iter.peek_back_nth(3), None);
+    assert!(iter.exhausted);
+
+    assert_eq!(iter.rev().collect::<Vec<_>>(), [2, 1, 0]);
+}
+
+#[test]
+fn meet_in_the_middle() {
+    let mut iter = (0..6).array_peekable::<6, 6>();
+
+    assert_eq!(iter.peek_nth(1), Some(&1));
+    assert_eq!(iter.peek_back_nth(1), Some(&4));
+    assert_eq!(items(&iter.front), [0, 1]);
+    assert_eq!(items(&iter.back), [5, 4]);
+
+    // Reaching the back buffer from the front
+    assert_eq!(iter.peek_nth(3), Some(&3));
+    assert_eq!(iter.peek_nth(4), Some(&4));
+    assert_eq!(iter.peek_nth(5), Some(&5));
+    assert_eq!(iter.peek_nth(6), None);
+    assert_eq!(items(&iter.front), [0, 1, 2, 3]);
+    assert_eq!(items(&iter.back), [5, 4]);
+
+    // Reaching the front buffer from the back
+    assert_eq!(iter.peek_back_nth(2), Some(&3));
+    assert_eq!(iter.peek_back_nth(5), Some(&0));
+    assert_eq!(iter.peek_back_nth(6), None);
+
+    assert_eq!(iter.next_back(), Some(5));
+    assert_eq!(iter.next_back(), Some(4));
+    // The back buffer is empty, the front buffer is consumed from its inner end
+    assert_eq!(iter.next_back(), Some(3));
+    assert_eq!(iter.peek_back(), Some(&2));
+    assert_eq!(iter.next(), Some(0));
+    assert_eq!(iter.next(), Some(1));
+    assert_eq!(iter.next(), Some(2));
+    assert_eq!(iter.peek(), None);
+    assert_eq!(iter.peek_back(), None);
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_back(), None);
+}
+
+#[test]
+fn exhausted_iterator_is_not_polled() {
+    fn assert_fused<I: FusedIterator>(_: &I) {}
+
+    let mut polled = 0;
+    let mut iter = core::iter::from_fn(|| {
+        polled += 1;
+        (polled == 1).then_some(0)
+    })
+    .array_peekable::<4, 4>();
+    // `FromFn` is not fused, the adapter is
+    assert_fused(&iter);
+
+    assert_eq!(iter.peek_nth(3), None);
+    assert_eq!(iter.next(), Some(0));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.peek_nth(0), None);
+    drop(iter);
+    assert_eq!(polled, 2);
+}
+
+#[test]
+fn size_hint() {
+    let mut iter = (0..6).array_peekable::<4, 4>();
+    assert_eq!(iter.size_hint(), (6, Some(6)));
+
+    assert_eq!(iter.peek_nth(1), Some(&1));
+    assert_eq!(iter.peek_back_nth(1), Some(&4));
+    assert_eq!(iter.size_hint(), (6, Some(6)));
+
+    assert_eq!(iter.next(), Some(0));
+    assert_eq!(iter.size_hint(), (5, Some(5)));
+
+    assert_eq!(iter.peek_nth(42), None);
+    assert_eq!(iter.size_hint(), (5, Some(5)));
+
+    let mut iter = (0..6).filter(|x| x % 2 == 0).array_peekable::<4, 4>();
+    assert_eq!(iter.size_hint(), (0, Some(6)));
+
+    assert_eq!(iter.peek_nth(1), Some(&2));
+    assert_eq!(iter.size_hint(), (2, Some(5)));
+
+    let iter = (0..).array_peekable::<4, 4>();
+    assert_eq!(iter.size_hint(), (usize::MAX, None));
+}
+
+#[test]
+fn exact_size_and_fused() {
+    fn assert_fused<I: FusedIterator>(_: &I) {}
+
+    let mut iter = (0..4).array_peekable::<2, 2>();
+    assert_fused(&iter);
+    assert_eq!(iter.len(), 4);
+
+    assert_eq!(iter.peek_nth(1), Some(&1));
+    assert_eq!(iter.peek_back(), Some(&3));
+    assert_eq!(iter.len(), 4);
+
+    assert_eq!(iter.next_back(), Some(3));
+    assert_eq!(iter.next_back(), Some(2));
+    assert_eq!(iter.len(), 2);
+    assert!(!iter.is_empty());
+
+    assert_eq!(iter.peek_back_nth(1), Some(&0));
+    assert_eq!(iter.len(), 2);
+    assert_eq!(iter.next(), Some(0));
+    assert_eq!(iter.next(), Some(1));
+    assert!(iter.is_empty());
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_back(), None);
+}
+
+#[test]
+fn clone() {
+    let mut iter = (0..5).array_peekable::<4, 4>();
+    assert_eq!(iter.peek_nth(1), Some(&1));
+    assert_eq!(iter.peek_back_nth(1), Some(&3));
+
+    assert_eq!(iter.clone().collect::<Vec<_>>(), [0, 1, 2, 3, 4]);
+    assert_eq!(iter.collect::<Vec<_>>(), [0, 1, 2, 3, 4]);
+}
+
+#[test]
+fn debug() {
+    let mut iter = [0, 1, 2, 3].into_iter().array_peekable::<2, 1>();
+    assert_eq!(iter.peek_nth(1), Some(&1));
+    assert_eq!(iter.peek_back(), Some(&3));
+
+    assert_eq!(
+        format!("{iter:?}"),
+        "MultiPeekable { iter: IntoIter([2]), front: [0, 1], back: [3], exhausted: false }",
+    );
+}
+
+#[test]
+fn partial_eq_and_hash() {
+    let mut iter = (0..5).array_peekable::<2, 2>();
+    assert_eq!(iter.peek_nth(1), Some(&1));
+    assert_eq!(iter.peek_back(), Some(&4));
+
+    let mut expected = (2..4).array_peekable::<2, 2>();
+    expected.front.push_inner(0);
+    expected.front.push_inner(1);
+    expected.back.push_inner(4);
+    assert_eq!(iter, expected);
+
+    let hash = |iter: &ArrayPeekable<_, 2, 2>| {
+        let mut hasher = DefaultHasher::default();
+        iter.hash(&mut hasher);
+        hasher.finish()
+    };
+    assert_eq!(hash(&iter), hash(&expected));
+
+    assert_eq!(iter.next(), Some(0));
+    assert_ne!(iter, expected);
+    assert_ne!(hash(&iter), hash(&expected));
+}
+
+#[test]
+fn capacity() {
+    let mut iter = (0..6).array_peekable::<2, 1>();
+
+    assert_eq!(iter.try_peek_nth(1), Ok(Some(&1)));
+    assert_eq!(iter.try_peek_nth(2), Err(CapacityError));
+    assert_eq!(iter.try_peek_nth_mut(2), Err(CapacityError));
+    assert_eq!(iter.peek_nth(2), None);
+    assert_eq!(iter.peek_nth_mut(2), None);
+    assert_eq!(items(&iter.front), [0, 1]);
+
+    assert_eq!(iter.try_peek_back_nth(0), Ok(Some(&5)));
+    assert_eq!(iter.try_peek_back_nth(1), Err(CapacityError));
+    assert_eq!(iter.try_peek_back_nth_mut(1), Err(CapacityError));
+    assert_eq!(iter.peek_back_nth(1), None);
+    assert_eq!(iter.peek_back_nth_mut(1), None);
+    assert_eq!(items(&iter.back), [5]);
+
+    assert_eq!(iter.next(), Some(0));
+    assert_eq!(iter.peek_nth(1), Some(&2));
+    assert_eq!(iter.next_back(), Some(5));
+    assert_eq!(iter.peek_back(), Some(&4));
+    assert_eq!(iter.collect::<Vec<_>>(), [1, 2, 3, 4]);
+}
+
+#[test]
+fn capacity_after_exhaustion() {
+    let mut iter = (0..3).array_peekable::<1, 1>();
+
+    assert_eq!(iter.peek_back(), Some(&2));
+    assert_eq!(iter.next(), Some(0));
+    assert_eq!(iter.peek(), Some(&1));
+    assert_eq!(iter.try_peek_nth(1), Err(CapacityError));
+
+    let mut iter = (0..2).array_peekable::<1, 1>();
+    assert_eq!(iter.peek_back(), Some(&1));
+    assert_eq!(iter.peek(), Some(&0));
+    assert_eq!(iter.try_peek_nth(1), Err(CapacityError));
+    assert_eq!(iter.next_back(), Some(1));
+    assert_eq!(iter.try_peek_back_nth(0), Ok(Some(&0)));
+    assert_eq!(iter.try_peek_nth(1), Ok(None));
+    assert!(iter.exhausted);
+}
+
+#[test]
+fn zero_capacity() {
+    let mut iter = (0..3).array_peekable::<0, 0>();
+
+    assert_eq!(iter.try_peek_nth(0), Err(CapacityError));
+    assert_eq!(iter.try_peek_back_nth(0), Err(CapacityError));
+    assert_eq!(iter.next(), Some(0));
+    assert_eq!(iter.next_back(), Some(2));
+    assert_eq!(iter.next(), Some(1));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.try_peek_nth(0), Ok(None));
+    assert_eq!(iter.try_peek_back_nth(0), Ok(None));
+}
+
+/// A tiny deterministic pseudo-random generator, in order to avoid external dependencies.
+struct Lcg(u64);
+
+impl Lcg {
+    fn next(&mut self) -> u64 {
+        self.0 = self
+            .0
+            .wrapping_mul(6_364_136_223_846_793_005)
+            .wrapping_add(1_442_695_040_888_963_407);
+        self.0 >> 33
+    }
+}
+
+#[test]
+fn try_push() {
+    let mut iter = (0..6).array_peekable::<2, 2>();
+    assert_eq!(iter.peek_nth(1), Some(&1));
+    assert_eq!(iter.try_push_front(-1), Err(-1));
+    assert_eq!(iter.next(), Some(0));
+    assert_eq!(iter.try_push_front(0), Ok(()));
+    assert_eq!(items(&iter.front), [0, 1]);
+
+    assert_eq!(iter.try_push_back(6), Ok(()));
+    assert_eq!(iter.peek_back_nth(1), Some(&5));
+    assert_eq!(iter.try_push_back(7), Err(7));
+    assert_eq!(items(&iter.back), [6, 5]);
+    assert_eq!(iter.size_hint(), (7, Some(7)));
+    assert_eq!(iter.clone().collect::<Vec<_>>(), [0, 1, 2, 3, 4, 5, 6]);
+
+    // Pushed items are reached from the opposite end once the iterator is exhausted
+    assert_eq!(iter.peek_back_nth(6), None);
+    assert_eq!(iter.rev().collect::<Vec<_>>(), [6, 5, 4, 3, 2, 1, 0]);
+
+    let mut iter = (0..0).array_peekable::<1, 0>();
+    assert_eq!(iter.try_push_back(0), Err(0));
+    assert_eq!(iter.try_push_front(0), Ok(()));
+    assert_eq!(iter.next_back(), Some(0));
+    assert_eq!(iter.next_back(), None);
+}
+
+#[test]
+fn starts_with_and_strip_prefix() {
+    let mut iter = (0..5).array_peekable::<3, 3>();
+    assert_eq!(iter.try_starts_with::<i32>(&[]), Ok(true));
+    assert_eq!(iter.try_starts_with(&[0, 1, 2]), Ok(true));
+    assert_eq!(iter.try_starts_with(&[0, 1, 3]), Ok(false));
+    assert_eq!(items(&iter.front), [0, 1, 2]);
+
+    assert_eq!(iter.try_strip_prefix(&[0, 2]), Ok(false));
+    assert_eq!(iter.try_strip_prefix(&[0, 1]), Ok(true));
+    assert_eq!(items(&iter.front), [2]);
+
+    // The items in the back buffer are reached once the iterator is exhausted
+    assert_eq!(iter.peek_back_nth(1), Some(&3));
+    assert_eq!(iter.try_starts_with(&[2, 3, 4]), Ok(true));
+    assert_eq!(iter.try_starts_with(&[2, 3, 5]), Ok(false));
+    assert_eq!(iter.try_starts_with(&[2, 3, 4, 5]), Err(CapacityError));
+    assert_eq!(iter.try_strip_prefix(&[2, 3, 4]), Ok(true));
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn ends_with_and_strip_suffix() {
+    let mut iter = (0..5).array_peekable::<3, 3>();
+    assert_eq!(iter.try_ends_with::<i32>(&[]), Ok(true));
+    assert_eq!(iter.try_ends_with(&[2, 3, 4]), Ok(true));
+    assert_eq!(iter.try_ends_with(&[1, 3, 4]), Ok(false));
+    assert_eq!(items(&iter.back), [4, 3, 2]);
+
+    assert_eq!(iter.try_strip_suffix(&[2, 4]), Ok(false));
+    assert_eq!(iter.try_strip_suffix(&[3, 4]), Ok(true));
+    assert_eq!(items(&iter.back), [2]);
+
+    assert_eq!(iter.peek_nth(1), Some(&1));
+    assert_eq!(iter.try_ends_with(&[0, 1, 2]), Ok(true));
+    assert_eq!(iter.try_ends_with(&[-1, 1, 2]), Ok(false));
+    assert_eq!(iter.try_ends_with(&[-1, 0, 1, 2]), Err(CapacityError));
+    assert_eq!(iter.try_strip_suffix(&[0, 1, 2]), Ok(true));
+    assert_eq!(iter.next_back(), None);
+}
+
+#[test]
+fn starts_with_longer_than_capacity() {
+    let mut iter = (0..5).array_peekable::<2, 0>();
+
+    // The outcome does not depend on the items
+    assert_eq!(iter.try_starts_with(&[0, 1, 2]), Err(CapacityError));
+    assert_eq!(iter.try_starts_with(&[9, 1, 2]), Err(CapacityError));
+    assert_eq!(iter.try_strip_prefix(&[0, 1, 2]), Err(CapacityError));
+    assert_eq!(iter.front.len(), 0);
+
+    assert_eq!(iter.try_strip_prefix(&[0, 1]), Ok(true));
+    assert_eq!(iter.next(), Some(2));
+}
+
+#[test]
+fn ends_with_longer_than_capacity() {
+    let mut iter = (0..5).array_peekable::<0, 2>();
+
+    assert_eq!(iter.try_ends_with(&[2, 3, 4]), Err(CapacityError));
+    assert_eq!(iter.try_ends_with(&[2, 3, 9]), Err(CapacityError));
+    assert_eq!(iter.try_strip_suffix(&[2, 3, 4]), Err(CapacityError));
+    assert_eq!(iter.back.len(), 0);
+
+    assert_eq!(iter.try_strip_suffix(&[3, 4]), Ok(true));
+    assert_eq!(iter.next_back(), Some(2));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn deque_starts_with_and_ends_with() {
+    let mut iter = (0..100).deque_peekable();
+    let prefix = (0..60).collect::<Vec<_>>();
+    let suffix = (50..100).collect::<Vec<_>>();
+
+    assert!(iter.starts_with(&prefix));
+    assert!(!iter.starts_with(&suffix));
+    assert!(iter.ends_with(&suffix));
+    assert!(!iter.ends_with(&prefix));
+
+    assert!(iter.strip_prefix(&prefix));
+    assert!(!iter.strip_suffix(&suffix));
+    assert!(iter.strip_suffix(&suffix[10..]));
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn next_chunk_if() {
+    let mut iter = (0..6).array_peekable::<3, 3>();
+    assert_eq!(iter.next_chunk_if(|_: &[_; 3]| false), None);
+    assert_eq!(items(&iter.front), [0, 1, 2]);
+    assert_eq!(iter.next(), Some(0));
+    assert_eq!(iter.try_push_front(-1), Ok(()));
+    // The buffer wraps around, but the chunk is contiguous
+    assert_eq!(
+        iter.next_chunk_if(|&chunk| chunk == [-1, 1, 2]),
+        Some([-1, 1, 2])
+    );
+    assert_eq!(iter.next_chunk_if_eq(&[]), Some([]));
+
+    // The items are taken from the back buffer once the iterator is exhausted
+    assert_eq!(iter.peek_back_nth(1), Some(&4));
+    assert_eq!(iter.next_chunk_if_eq(&[3, 4]), Some([3, 4]));
+    assert_eq!(iter.next_chunk_if(|_: &[_; 2]| true), None);
+    assert_eq!(items(&iter.front), [5]);
+    assert_eq!(iter.next_back(), Some(5));
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn next_back_chunk_if() {
+    let mut iter = (0..6).array_peekable::<3, 3>();
+    let mut inspected = None;
+    assert_eq!(
+        iter.next_back_chunk_if(|&chunk: &[_; 3]| {
+            inspected = Some(chunk);
+            false
+        }),
+        None
+    );
+    assert_eq!(inspected, Some([3, 4, 5]));
+    assert_eq!(items(&iter.back), [5, 4, 3]);
+    assert_eq!(iter.next_back_chunk_if_eq(&[4, 5]), Some([4, 5]));
+    assert_eq!(iter.next_back_chunk_if_eq(&[]), Some([]));
+
+    assert_eq!(iter.peek_nth(1), Some(&1));
+    assert_eq!(iter.next_back_chunk_if_eq(&[1, 2, 3]), Some([1, 2, 3]));
+    assert_eq!(iter.next_back_chunk_if(|_: &[_; 2]| true), None);
+    assert_eq!(items(&iter.back), [0]);
+    assert_eq!(iter.next(), Some(0));
+}
+
+#[test]
+fn next_chunk_if_longer_than_capacity() {
+    // The outcome does not depend on the number of remaining items
+    for len in [2, 5] {
+        let mut iter = (0..len).array_peekable::<2, 0>();
+        assert_eq!(iter.next_chunk_if(|_: &[_; 3]| true), None);
+        assert_eq!(iter.next_chunk_if_eq(&[0, 1, 2]), None);
+        assert_eq!(iter.front.len(), 0);
+        assert_eq!(iter.next(), Some(0));
+    }
+}
+
+#[test]
+fn next_back_chunk_if_panicking() {
+    let mut iter = (0..6).array_peekable::<0, 3>();
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        iter.next_back_chunk_if(|_: &[_; 3]| panic!("rejected"));
+    }));
+    assert!(result.is_err());
+
+    // The order of the back buffer is restored
+    assert_eq!(items(&iter.back), [5, 4, 3]);
+    assert_eq!(iter.next_back(), Some(5));
+    assert_eq!(iter.next_back_chunk_if_eq(&[3, 4]), Some([3, 4]));
+}
+
+#[test]
+fn next_back_chunk_if_longer_than_capacity() {
+    let mut iter = (0..3).array_peekable::<3, 2>();
+    assert_eq!(iter.peek_nth(2), Some(&2));
+    assert_eq!(iter.next_back_chunk_if(|_: &[_; 3]| true), None);
+    assert_eq!(iter.next_back_chunk_if_eq(&[0, 1, 2]), None);
+    assert_eq!(iter.back.len(), 0);
+    assert_eq!(iter.next_back(), Some(2));
+
+    let mut iter = (0..5).array_peekable::<0, 2>();
+    assert_eq!(iter.next_back_chunk_if(|_: &[_; 3]| true), None);
+    assert_eq!(iter.back.len(), 0);
+    assert_eq!(iter.next_back(), Some(4));
+}
+
+#[test]
+fn behaves_like_double_ended_peekable() {
+    for seed in 0..200 {
+        let mut rng = Lcg(seed);
+        let len = usize::try_from(rng.next() % 8).unwrap();
+        let mut expected = (0..len).double_ended_peekable();
+        let mut iter = (0..len).array_peekable::<1, 1>();
+
+        for _ in 0..20 {
+            let pivot = usize::try_from(rng.next() % 8).unwrap();
+            let below = |&item: &usize| item < pivot;
+            match rng.next() % 14 {
+                0 => assert_eq!(iter.peek(), expected.peek()),
+                1 => assert_eq!(iter.peek_mut(), expected.peek_mut()),
+                2 => assert_eq!(iter.peek_back(), expected.peek_back()),
+                3 => assert_eq!(iter.peek_back_mut(), expected.peek_back_mut()),
+                4 | 5 => assert_eq!(iter.next(), expected.next()),
+                6 | 7 => assert_eq!(iter.next_back(), expected.next_back()),
+                8 => assert_eq!(iter.next_if(below), expected.next_if(below)),
+                9 => assert_eq!(iter.next_if_eq(&pivot), expected.next_if_eq(&pivot)),
+                10 => assert_eq!(iter.next_back_if(below), expected.next_back_if(below)),
+                11 => assert_eq!(
+                    iter.next_back_if_eq(&pivot),
+                    expected.next_back_if_eq(&pivot)
+                ),
+                12 => assert_eq!(
+                    iter.next_front_back_if(|&front, &back| front + back < pivot * 2),
+                    expected.next_front_back_if(|&front, &back| front + back < pivot * 2)
+                ),
+                _ => assert_eq!(
+                    iter.next_front_back_if_eq(&pivot, &len.saturating_sub(1)),
+                    expected.next_front_back_if_eq(&pivot, &len.saturating_sub(1))
+                ),
+            }
+            assert_eq!(iter.size_hint(), expected.size_hint());
+        }
+    }
+}
+
+#[test]
+fn behaves_like_double_ended_peekable_when_peeking_past_end() {
+    let mut expected: DoubleEndedPeekable<_> = (0..3).double_ended_peekable();
+    let mut iter = (0..3).array_peekable::<1, 1>();
+
+    for _ in 0..3 {
+        assert_eq!(iter.peek_back(), expected.peek_back());
+        assert_eq!(iter.next(), expected.next());
+    }
+    assert_eq!(iter.peek(), expected.peek());
+    assert_eq!(iter.peek_back(), expected.peek_back());
+    assert_eq!(iter.next(), expected.next());
+    assert_eq!(iter.next_back(), expected.next_back());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn deque_meet_in_the_middle() {
+    let mut iter = (0..100).deque_peekable();
+
+    assert_eq!(iter.peek_nth(40), Some(&40));
+    assert_eq!(iter.peek_back_nth(40), Some(&59));
+    assert_eq!(iter.peek_nth(80), Some(&80));
+    assert_eq!(iter.try_peek_nth(100), Ok(None));
+    assert_eq!(iter.peek_back_nth(99), Some(&0));
+    assert_eq!(iter.size_hint(), (100, Some(100)));
+    assert!(iter.by_ref().eq(0..100));
+    assert_eq!(iter.front.len(), 0);
+    assert_eq!(iter.back.len(), 0);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn peek_range() {
+    let mut iter = (0..6).deque_peekable();
+
+    assert!(iter.peek_range(0..0).eq(&[]));
+    assert_eq!(iter.front.len(), 0);
+
+    assert!(iter.peek_range(1..=2).eq(&[1, 2]));
+    assert_eq!(iter.front, [0, 1, 2]);
+    assert!(!iter.exhausted);
+
+    assert_eq!(iter.peek_back_nth(1), Some(&4));
+    assert_eq!(iter.back, [5, 4]);
+
+    let range = iter.peek_range(2..5);
+    assert_eq!(range.len(), 3);
+    assert!(range.eq(&[2, 3, 4]));
+    assert_eq!(iter.front, [0, 1, 2, 3]);
+    assert!(iter.exhausted);
+
+    assert!(iter.peek_range(..).eq(&[0, 1, 2, 3, 4, 5]));
+    assert!(iter.peek_range(..).rev().eq(&[5, 4, 3, 2, 1, 0]));
+    assert!(iter.peek_range(5..).eq(&[5]));
+    assert!(iter.peek_range(4..42).eq(&[4, 5]));
+    assert!(iter.peek_range(42..).eq(&[]));
+    #[allow(clippy::reversed_empty_ranges)]
+    let reversed = 4..2;
+    assert!(iter.peek_range(reversed).eq(&[]));
+    assert!(iter
+        .peek_range((Bound::Excluded(2), Bound::Included(usize::MAX)))
+        .eq(&[3, 4, 5]));
+
+    assert!(iter.by_ref().eq(0..6));
+    assert!(iter.peek_range(..).eq(&[]));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn peek_back_range() {
+    let mut iter = (0..6).deque_peekable();
+
+    assert!(iter.peek_back_range(..0).eq(&[]));
+    assert_eq!(iter.back.len(), 0);
+
+    assert!(iter.peek_back_range(1..=2).eq(&[4, 3]));
+    assert_eq!(iter.back, [5, 4, 3]);
+    assert!(!iter.exhausted);
+
+    assert_eq!(iter.peek_nth(0), Some(&0));
+    assert!(iter.peek_back_range(..).eq(&[5, 4, 3, 2, 1, 0]));
+    assert_eq!(iter.front, [0]);
+    assert_eq!(iter.back, [5, 4, 3, 2, 1]);
+    assert!(iter.peek_back_range(4..).eq(&[1, 0]));
+    assert!(iter.peek_back_range(5..).rev().eq(&[0]));
+
+    assert_eq!(iter.next(), Some(0));
+    assert_eq!(iter.next_back(), Some(5));
+    assert!(iter.peek_range(..).eq(&[1, 2, 3, 4]));
+    assert!(iter.peek_back_range(..).eq(&[4, 3, 2, 1]));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn deque_push() {
+    let mut iter = (0..2).deque_peekable();
+    for item in (-3..0).rev() {
+        iter.push_front(item);
+    }
+    for item in 2..5 {
+        iter.push_back(item);
+    }
+    assert_eq!(items(&iter.front), [-3, -2, -1]);
+    assert_eq!(items(&iter.back), [4, 3, 2]);
+    assert_eq!(iter.try_push_front(-4), Ok(()));
+    assert_eq!(iter.len(), 9);
+    assert_eq!(iter.collect::<Vec<_>>(), (-4..5).collect::<Vec<_>>());
+}
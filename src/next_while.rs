@@ -0,0 +1,165 @@
+#[cfg(test)]
+mod tests;
+
+use core::{
+    fmt::{self, Debug},
+    iter::FusedIterator,
+};
+
+use crate::DoubleEndedPeekable;
+
+/// An iterator that consumes the items from the front of a [`DoubleEndedPeekable`] as long as
+/// they satisfy a predicate.
+///
+/// The first item that does not satisfy the predicate is not lost, it stays peeked at the front
+/// of the underlying [`DoubleEndedPeekable`].
+///
+/// This `struct` is created by [`DoubleEndedPeekable::next_while`].
+pub struct NextWhile<'a, I, P>
+where
+    I: Iterator,
+{
+    peekable: &'a mut DoubleEndedPeekable<I>,
+    predicate: P,
+    finished: bool,
+}
+
+impl<'a, I, P> NextWhile<'a, I, P>
+where
+    I: Iterator,
+{
+    #[inline]
+    pub(crate) fn new(peekable: &'a mut DoubleEndedPeekable<I>, predicate: P) -> Self {
+        Self {
+            peekable,
+            predicate,
+            finished: false,
+        }
+    }
+}
+
+impl<I, P> Iterator for NextWhile<'_, I, P>
+where
+    I: Iterator,
+    P: FnMut(&I::Item) -> bool,
+{
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+
+        let item = self.peekable.next_if(&mut self.predicate);
+        self.finished = item.is_none();
+        item
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.finished {
+            (0, Some(0))
+        } else {
+            (0, self.peekable.size_hint().1)
+        }
+    }
+}
+
+impl<I, P> FusedIterator for NextWhile<'_, I, P>
+where
+    I: Iterator,
+    P: FnMut(&I::Item) -> bool,
+{
+}
+
+impl<I, P> Debug for NextWhile<'_, I, P>
+where
+    I: Iterator + Debug,
+    I::Item: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NextWhile")
+            .field("peekable", &self.peekable)
+            .field("finished", &self.finished)
+            .finish_non_exhaustive()
+    }
+}
+
+/// An iterator that consumes the items from the back of a [`DoubleEndedPeekable`] as long as they
+/// satisfy a predicate.
+///
+/// The first item that does not satisfy the predicate is not lost, it stays peeked at the back of
+/// the underlying [`DoubleEndedPeekable`].
+///
+/// This `struct` is created by [`DoubleEndedPeekable::next_back_while`].
+pub struct NextBackWhile<'a, I, P>
+where
+    I: Iterator,
+{
+    peekable: &'a mut DoubleEndedPeekable<I>,
+    predicate: P,
+    finished: bool,
+}
+
+impl<'a, I, P> NextBackWhile<'a, I, P>
+where
+    I: Iterator,
+{
+    #[inline]
+    pub(crate) fn new(peekable: &'a mut DoubleEndedPeekable<I>, predicate: P) -> Self {
+        Self {
+            peekable,
+            predicate,
+            finished: false,
+        }
+    }
+}
+
+impl<I, P> Iterator for NextBackWhile<'_, I, P>
+where
+    I: DoubleEndedIterator,
+    P: FnMut(&I::Item) -> bool,
+{
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+
+        let item = self.peekable.next_back_if(&mut self.predicate);
+        self.finished = item.is_none();
+        item
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.finished {
+            (0, Some(0))
+        } else {
+            (0, self.peekable.size_hint().1)
+        }
+    }
+}
+
+impl<I, P> FusedIterator for NextBackWhile<'_, I, P>
+where
+    I: DoubleEndedIterator,
+    P: FnMut(&I::Item) -> bool,
+{
+}
+
+impl<I, P> Debug for NextBackWhile<'_, I, P>
+where
+    I: Iterator + Debug,
+    I::Item: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NextBackWhile")
+            .field("peekable", &self.peekable)
+            .field("finished", &self.finished)
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,61 @@
+use crate::DoubleEndedPeekableExt;
+
+#[test]
+fn next_while_keeps_the_boundary_item() {
+    let mut iter = [0, 1, 5, 2, 6, 3].into_iter().double_ended_peekable();
+
+    let mut next_while = iter.next_while(|&item| item < 4);
+    assert_eq!(next_while.size_hint(), (0, Some(6)));
+    assert_eq!(next_while.next(), Some(0));
+    assert_eq!(next_while.next(), Some(1));
+    assert_eq!(next_while.next(), None);
+    assert_eq!(next_while.size_hint(), (0, Some(0)));
+    assert_eq!(iter.peek(), Some(&5));
+
+    let skipped_back = iter.next_back_while(|&item| item < 4).collect::<Vec<_>>();
+    assert_eq!(skipped_back, [3]);
+    assert_eq!(iter.peek_back(), Some(&6));
+    assert_eq!(iter.collect::<Vec<_>>(), [5, 2, 6]);
+}
+
+#[test]
+fn next_while_is_fused() {
+    let mut calls = 0;
+    let mut iter = (0..4).double_ended_peekable();
+    let mut next_while = iter.next_while(|&item| {
+        calls += 1;
+        item != 1
+    });
+
+    assert_eq!(next_while.next(), Some(0));
+    assert_eq!(next_while.next(), None);
+    assert_eq!(next_while.next(), None);
+    assert_eq!(calls, 2);
+}
+
+#[test]
+fn next_while_meets_the_other_end() {
+    let mut iter = (0..3).double_ended_peekable();
+    assert_eq!(iter.peek_back(), Some(&2));
+    assert_eq!(iter.skip_while_front(|_| true), 3);
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_back(), None);
+
+    let mut iter = (0..3).double_ended_peekable();
+    assert_eq!(iter.peek(), Some(&0));
+    assert!(iter.next_back_while(|_| true).eq([2, 1, 0]));
+    assert_eq!(iter.next(), None);
+
+    let mut iter = (0..0).double_ended_peekable();
+    assert_eq!(iter.skip_while_back(|_| true), 0);
+}
+
+#[test]
+fn debug() {
+    let mut iter = (0..2).double_ended_peekable();
+    let next_while = iter.next_while(|_| true);
+    assert_eq!(
+        format!("{next_while:?}"),
+        "NextWhile { peekable: DoubleEndedPeekable { iter: 0..2, front: Unpeeked, back: Unpeeked }, finished: false, .. }"
+    );
+}
@@ -0,0 +1,271 @@
+#[cfg(test)]
+mod tests;
+
+use core::iter::Peekable;
+
+use crate::{buffer::Buffer, BackView, DoubleEndedPeekable, FrontView, MultiPeekable};
+
+/// An iterator able to peek its next item.
+///
+/// This trait allows to write algorithms that work with any peekable adapter, like
+/// [`DoubleEndedPeekable`], [`MultiPeekable`], the views over the ends of a
+/// [`DoubleEndedPeekable`] and [`Peekable`] from the standard library.
+///
+/// The methods have the same meaning of the ones of [`Peekable`].
+///
+/// # Examples
+///
+/// ```
+/// use double_ended_peekable::{DoubleEndedPeekableExt, PeekFront};
+///
+/// fn skip_zeros<I: PeekFront<Item = u8>>(iter: &mut I) -> usize {
+///     let mut skipped = 0;
+///     while iter.next_if_eq(&0).is_some() {
+///         skipped += 1;
+///     }
+///     skipped
+/// }
+///
+/// let mut iter = [0, 0, 1].into_iter().peekable();
+/// assert_eq!(skip_zeros(&mut iter), 2);
+///
+/// let mut iter = [0, 1, 0].into_iter().double_ended_peekable();
+/// assert_eq!(skip_zeros(&mut iter), 1);
+/// assert_eq!(skip_zeros(&mut iter.back_view()), 1);
+/// assert_eq!(iter.next(), Some(1));
+/// ```
+pub trait PeekFront: Iterator {
+    /// Returns a reference to the `next()` value without advancing the iterator.
+    fn peek(&mut self) -> Option<&Self::Item>;
+
+    /// Returns a mutable reference to the `next()` value without advancing the iterator.
+    fn peek_mut(&mut self) -> Option<&mut Self::Item>;
+
+    /// Consumes and returns the next value of this iterator if a condition is true.
+    fn next_if(&mut self, func: impl FnOnce(&Self::Item) -> bool) -> Option<Self::Item>;
+
+    /// Consumes and returns the next item if it is equal to `expected`.
+    #[inline]
+    fn next_if_eq<T>(&mut self, expected: &T) -> Option<Self::Item>
+    where
+        T: ?Sized,
+        Self::Item: PartialEq<T>,
+    {
+        self.next_if(|item| item == expected)
+    }
+}
+
+/// A double-ended iterator able to peek its _next back_ item.
+///
+/// This is the counterpart of [`PeekFront`] for the back of the iterator. It is implemented by
+/// [`DoubleEndedPeekable`] and [`MultiPeekable`].
+///
+/// # Examples
+///
+/// ```
+/// use double_ended_peekable::{DoubleEndedPeekableExt, PeekBack, PeekFront};
+///
+/// fn trim_zeros<I: PeekFront<Item = u8> + PeekBack>(iter: &mut I) {
+///     while iter.next_if_eq(&0).is_some() {}
+///     while iter.next_back_if_eq(&0).is_some() {}
+/// }
+///
+/// let mut iter = [0, 1, 2, 0].into_iter().double_ended_peekable();
+/// trim_zeros(&mut iter);
+/// assert_eq!(iter.collect::<Vec<_>>(), [1, 2]);
+///
+/// let mut iter = [0, 1, 2, 0].into_iter().array_peekable::<1, 1>();
+/// trim_zeros(&mut iter);
+/// assert_eq!(iter.collect::<Vec<_>>(), [1, 2]);
+/// ```
+pub trait PeekBack: DoubleEndedIterator {
+    /// Returns a reference to the `next_back()` value without advancing the _back_ of the
+    /// iterator.
+    fn peek_back(&mut self) -> Option<&Self::Item>;
+
+    /// Returns a mutable reference to the `next_back()` value without advancing the _back_ of the
+    /// iterator.
+    fn peek_back_mut(&mut self) -> Option<&mut Self::Item>;
+
+    /// Consumes and returns the _next back_ value of this iterator if a condition is true.
+    fn next_back_if(&mut self, func: impl FnOnce(&Self::Item) -> bool) -> Option<Self::Item>;
+
+    /// Consumes and returns the _next back_ item if it is equal to `expected`.
+    #[inline]
+    fn next_back_if_eq<T>(&mut self, expected: &T) -> Option<Self::Item>
+    where
+        T: ?Sized,
+        Self::Item: PartialEq<T>,
+    {
+        self.next_back_if(|item| item == expected)
+    }
+}
+
+impl<I: Iterator> PeekFront for Peekable<I> {
+    #[inline]
+    fn peek(&mut self) -> Option<&Self::Item> {
+        Peekable::peek(self)
+    }
+
+    #[inline]
+    fn peek_mut(&mut self) -> Option<&mut Self::Item> {
+        Peekable::peek_mut(self)
+    }
+
+    #[inline]
+    fn next_if(&mut self, func: impl FnOnce(&Self::Item) -> bool) -> Option<Self::Item> {
+        Peekable::next_if(self, func)
+    }
+}
+
+impl<I: Iterator> PeekFront for DoubleEndedPeekable<I> {
+    #[inline]
+    fn peek(&mut self) -> Option<&Self::Item> {
+        DoubleEndedPeekable::peek(self)
+    }
+
+    #[inline]
+    fn peek_mut(&mut self) -> Option<&mut Self::Item> {
+        DoubleEndedPeekable::peek_mut(self)
+    }
+
+    #[inline]
+    fn next_if(&mut self, func: impl FnOnce(&Self::Item) -> bool) -> Option<Self::Item> {
+        DoubleEndedPeekable::next_if(self, func)
+    }
+}
+
+impl<I: DoubleEndedIterator> PeekBack for DoubleEndedPeekable<I> {
+    #[inline]
+    fn peek_back(&mut self) -> Option<&Self::Item> {
+        DoubleEndedPeekable::peek_back(self)
+    }
+
+    #[inline]
+    fn peek_back_mut(&mut self) -> Option<&mut Self::Item> {
+        DoubleEndedPeekable::peek_back_mut(self)
+    }
+
+    #[inline]
+    fn next_back_if(&mut self, func: impl FnOnce(&Self::Item) -> bool) -> Option<Self::Item> {
+        DoubleEndedPeekable::next_back_if(self, func)
+    }
+}
+
+impl<I: Iterator> PeekFront for FrontView<'_, I> {
+    #[inline]
+    fn peek(&mut self) -> Option<&Self::Item> {
+        FrontView::peek(self)
+    }
+
+    #[inline]
+    fn peek_mut(&mut self) -> Option<&mut Self::Item> {
+        FrontView::peek_mut(self)
+    }
+
+    #[inline]
+    fn next_if(&mut self, func: impl FnOnce(&Self::Item) -> bool) -> Option<Self::Item> {
+        FrontView::next_if(self, func)
+    }
+}
+
+impl<I: DoubleEndedIterator> PeekFront for BackView<'_, I> {
+    #[inline]
+    fn peek(&mut self) -> Option<&Self::Item> {
+        BackView::peek(self)
+    }
+
+    #[inline]
+    fn peek_mut(&mut self) -> Option<&mut Self::Item> {
+        BackView::peek_mut(self)
+    }
+
+    #[inline]
+    fn next_if(&mut self, func: impl FnOnce(&Self::Item) -> bool) -> Option<Self::Item> {
+        BackView::next_if(self, func)
+    }
+}
+
+impl<I, F, B> PeekFront for MultiPeekable<I, F, B>
+where
+    I: Iterator,
+    F: Buffer<I::Item>,
+    B: Buffer<I::Item>,
+{
+    #[inline]
+    fn peek(&mut self) -> Option<&Self::Item> {
+        MultiPeekable::peek(self)
+    }
+
+    #[inline]
+    fn peek_mut(&mut self) -> Option<&mut Self::Item> {
+        MultiPeekable::peek_mut(self)
+    }
+
+    #[inline]
+    fn next_if(&mut self, func: impl FnOnce(&Self::Item) -> bool) -> Option<Self::Item> {
+        MultiPeekable::next_if(self, func)
+    }
+}
+
+impl<I, F, B> PeekBack for MultiPeekable<I, F, B>
+where
+    I: DoubleEndedIterator,
+    F: Buffer<I::Item>,
+    B: Buffer<I::Item>,
+{
+    #[inline]
+    fn peek_back(&mut self) -> Option<&Self::Item> {
+        MultiPeekable::peek_back(self)
+    }
+
+    #[inline]
+    fn peek_back_mut(&mut self) -> Option<&mut Self::Item> {
+        MultiPeekable::peek_back_mut(self)
+    }
+
+    #[inline]
+    fn next_back_if(&mut self, func: impl FnOnce(&Self::Item) -> bool) -> Option<Self::Item> {
+        MultiPeekable::next_back_if(self, func)
+    }
+}
+
+impl<P> PeekFront for &mut P
+where
+    P: PeekFront + ?Sized,
+{
+    #[inline]
+    fn peek(&mut self) -> Option<&Self::Item> {
+        P::peek(self)
+    }
+
+    #[inline]
+    fn peek_mut(&mut self) -> Option<&mut Self::Item> {
+        P::peek_mut(self)
+    }
+
+    #[inline]
+    fn next_if(&mut self, func: impl FnOnce(&Self::Item) -> bool) -> Option<Self::Item> {
+        P::next_if(self, func)
+    }
+}
+
+impl<P> PeekBack for &mut P
+where
+    P: PeekBack + ?Sized,
+{
+    #[inline]
+    fn peek_back(&mut self) -> Option<&Self::Item> {
+        P::peek_back(self)
+    }
+
+    #[inline]
+    fn peek_back_mut(&mut self) -> Option<&mut Self::Item> {
+        P::peek_back_mut(self)
+    }
+
+    #[inline]
+    fn next_back_if(&mut self, func: impl FnOnce(&Self::Item) -> bool) -> Option<Self::Item> {
+        P::next_back_if(self, func)
+    }
+}
@@ -0,0 +1,65 @@
+use crate::DoubleEndedPeekableExt;
+
+use super::*;
+
+/// Skips the items equal to `skipped` from the front, returning the next one.
+fn skip_front<P>(mut iter: P, skipped: i32) -> Option<i32>
+where
+    P: PeekFront<Item = i32>,
+{
+    while iter.next_if_eq(&skipped).is_some() {}
+    iter.peek().copied()
+}
+
+/// Skips the items equal to `skipped` from the back, returning the next back one.
+fn skip_back<P>(mut iter: P, skipped: i32) -> Option<i32>
+where
+    P: PeekBack<Item = i32>,
+{
+    while iter.next_back_if_eq(&skipped).is_some() {}
+    iter.peek_back().copied()
+}
+
+#[test]
+fn peek_front() {
+    let items = [0, 0, 1, 2, 0];
+
+    let mut iter = items.into_iter().peekable();
+    assert_eq!(skip_front(&mut iter, 0), Some(1));
+    assert!(iter.eq([1, 2, 0]));
+
+    let mut iter = items.into_iter().double_ended_peekable();
+    assert_eq!(skip_front(&mut iter, 0), Some(1));
+    assert_eq!(skip_front(iter.front_view(), 1), Some(2));
+    assert_eq!(skip_front(iter.back_view(), 0), Some(2));
+    assert!(iter.eq([2]));
+
+    let mut iter = items.into_iter().array_peekable::<1, 1>();
+    assert_eq!(skip_front(&mut iter, 0), Some(1));
+    *PeekFront::peek_mut(&mut iter).unwrap() = 3;
+    assert!(iter.eq([3, 2, 0]));
+}
+
+#[test]
+fn peek_back() {
+    let items = [0, 1, 2, 0, 0];
+
+    let mut iter = items.into_iter().double_ended_peekable();
+    assert_eq!(skip_back(&mut iter, 0), Some(2));
+    *PeekBack::peek_back_mut(&mut iter).unwrap() = 3;
+    assert!(iter.eq([0, 1, 3]));
+
+    let mut iter = items.into_iter().array_peekable::<0, 1>();
+    assert_eq!(skip_back(&mut iter, 0), Some(2));
+    assert_eq!(skip_back(&mut iter, 2), Some(1));
+    assert!(iter.eq([0, 1]));
+}
+
+#[test]
+fn multi_peekable_with_zero_capacity() {
+    // Nothing can be peeked, therefore nothing is consumed
+    let mut iter = [0, 1].into_iter().array_peekable::<0, 0>();
+    assert_eq!(PeekFront::next_if(&mut iter, |_| true), None);
+    assert_eq!(PeekBack::next_back_if(&mut iter, |_| true), None);
+    assert!(iter.eq([0, 1]));
+}
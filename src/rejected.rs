@@ -0,0 +1,36 @@
+/// The reason why an item has not been consumed by a conditional method like
+/// [`DoubleEndedPeekable::try_next_if`].
+///
+/// [`DoubleEndedPeekable::try_next_if`]: crate::DoubleEndedPeekable::try_next_if
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Rejected<'a, T> {
+    /// There are no items left.
+    Exhausted,
+
+    /// The item did not satisfy the condition. It is still peeked.
+    Mismatch(&'a T),
+}
+
+impl<'a, T> Rejected<'a, T> {
+    /// Returns `true` if there are no items left.
+    #[inline]
+    pub const fn is_exhausted(&self) -> bool {
+        matches!(self, Self::Exhausted)
+    }
+
+    /// Returns the item that did not satisfy the condition, if any.
+    #[inline]
+    pub const fn mismatch(self) -> Option<&'a T> {
+        match self {
+            Self::Exhausted => None,
+            Self::Mismatch(item) => Some(item),
+        }
+    }
+}
+
+impl<'a, T> From<Option<&'a T>> for Rejected<'a, T> {
+    #[inline]
+    fn from(item: Option<&'a T>) -> Self {
+        item.map_or(Self::Exhausted, Self::Mismatch)
+    }
+}
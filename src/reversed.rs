@@ -0,0 +1,89 @@
+#[cfg(test)]
+mod tests;
+
+use core::iter::FusedIterator;
+
+/// A double-ended iterator with the direction inverted.
+///
+/// This is like [`Rev`], except that the underlying iterator can be retrieved using
+/// [`into_inner`]. It is used by [`DoubleEndedPeekable::reversed`] in order to be undone by
+/// [`DoubleEndedPeekable::unreversed`].
+///
+/// [`Rev`]: core::iter::Rev
+/// [`into_inner`]: Self::into_inner
+/// [`DoubleEndedPeekable::reversed`]: crate::DoubleEndedPeekable::reversed
+/// [`DoubleEndedPeekable::unreversed`]: crate::DoubleEndedPeekable::unreversed
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Reversed<I> {
+    iter: I,
+}
+
+impl<I> Reversed<I> {
+    #[inline]
+    pub(crate) const fn new(iter: I) -> Self {
+        Self { iter }
+    }
+
+    /// Returns the underlying iterator.
+    #[inline]
+    pub fn into_inner(self) -> I {
+        self.iter
+    }
+}
+
+impl<I> Iterator for Reversed<I>
+where
+    I: DoubleEndedIterator,
+{
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next_back()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.iter.nth_back(n)
+    }
+
+    #[inline]
+    fn fold<B, F>(self, init: B, f: F) -> B
+    where
+        F: FnMut(B, Self::Item) -> B,
+    {
+        self.iter.rfold(init, f)
+    }
+}
+
+impl<I> DoubleEndedIterator for Reversed<I>
+where
+    I: DoubleEndedIterator,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next()
+    }
+
+    #[inline]
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        self.iter.nth(n)
+    }
+
+    #[inline]
+    fn rfold<B, F>(self, init: B, f: F) -> B
+    where
+        F: FnMut(B, Self::Item) -> B,
+    {
+        self.iter.fold(init, f)
+    }
+}
+
+impl<I> ExactSizeIterator for Reversed<I> where I: ExactSizeIterator + DoubleEndedIterator {}
+
+impl<I> FusedIterator for Reversed<I> where I: FusedIterator + DoubleEndedIterator {}
@@ -0,0 +1,29 @@
+use super::*;
+
+#[test]
+fn iterates_backwards() {
+    let mut iter = Reversed::new(0..6);
+    assert_eq!(iter.size_hint(), (6, Some(6)));
+    assert_eq!(iter.next(), Some(5));
+    assert_eq!(iter.next_back(), Some(0));
+    assert_eq!(iter.nth(1), Some(3));
+    assert_eq!(iter.nth_back(0), Some(1));
+    assert_eq!(iter.len(), 1);
+    assert_eq!(iter.clone().into_inner(), 2..3);
+
+    let iter = Reversed::new(0..4);
+    assert_eq!(
+        iter.clone().fold(Vec::new(), |mut acc, item| {
+            acc.push(item);
+            acc
+        }),
+        [3, 2, 1, 0]
+    );
+    assert_eq!(
+        iter.rfold(Vec::new(), |mut acc, item| {
+            acc.push(item);
+            acc
+        }),
+        [0, 1, 2, 3]
+    );
+}
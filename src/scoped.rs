@@ -0,0 +1,129 @@
+#[cfg(test)]
+mod tests;
+
+use core::iter::FusedIterator;
+
+use crate::{DoubleEndedPeekable, DoubleEndedPeekableExt};
+
+/// Runs `f` with a [`DoubleEndedPeekable`] borrowing `iter`, returning the result of `f` together
+/// with the items that are still peeked at the end of the scope.
+///
+/// Creating a peekable adapter on the fly over `iter.by_ref()` is a footgun, because the items
+/// peeked but not consumed are dropped together with the adapter. This function hands them back
+/// to the caller instead, as [`Leftovers`].
+///
+/// # Examples
+///
+/// ```
+/// use double_ended_peekable::with_double_ended_peekable;
+///
+/// let mut iter = [1, 2, 3, 4, 5].into_iter();
+///
+/// let (sum, leftovers) = with_double_ended_peekable(&mut iter, |iter| {
+///     let mut sum = 0;
+///     while let Some(item) = iter.next_if(|&item| item < 3) {
+///         sum += item;
+///     }
+///     assert_eq!(iter.peek_back(), Some(&5));
+///     sum
+/// });
+///
+/// assert_eq!(sum, 3);
+/// // The items peeked from both ends are not lost
+/// assert_eq!(leftovers.front(), Some(&3));
+/// assert_eq!(leftovers.back(), Some(&5));
+/// assert_eq!(iter.as_slice(), [4]);
+/// ```
+pub fn with_double_ended_peekable<I, F, R>(iter: &mut I, f: F) -> (R, Leftovers<I::Item>)
+where
+    I: Iterator,
+    F: FnOnce(&mut DoubleEndedPeekable<&mut I>) -> R,
+{
+    let mut peekable = iter.double_ended_peekable();
+    let out = f(&mut peekable);
+    let (front, _, back) = peekable.into_parts();
+
+    (
+        out,
+        Leftovers {
+            front: front.flatten(),
+            back: back.flatten(),
+        },
+    )
+}
+
+/// The items that were still peeked from a [`DoubleEndedPeekable`] when it went out of scope.
+///
+/// It is an iterator that yields the item peeked from the front, if any, and then the item peeked
+/// from the back, if any. Therefore, the leftovers need to be put before the remaining items of
+/// the original iterator in order to get back the item peeked from the front, and after them for
+/// the one peeked from the back.
+///
+/// This `struct` is created by [`with_double_ended_peekable`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Leftovers<T> {
+    front: Option<T>,
+    back: Option<T>,
+}
+
+impl<T> Leftovers<T> {
+    /// Returns a reference to the item peeked from the front, if any.
+    #[inline]
+    pub const fn front(&self) -> Option<&T> {
+        self.front.as_ref()
+    }
+
+    /// Returns a reference to the item peeked from the back, if any.
+    #[inline]
+    pub const fn back(&self) -> Option<&T> {
+        self.back.as_ref()
+    }
+
+    /// Returns the items peeked from the front and from the back, respectively.
+    #[inline]
+    pub fn into_parts(self) -> (Option<T>, Option<T>) {
+        (self.front, self.back)
+    }
+
+    /// Returns `true` if there are no leftovers.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.front.is_none() && self.back.is_none()
+    }
+}
+
+impl<T> Default for Leftovers<T> {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            front: None,
+            back: None,
+        }
+    }
+}
+
+impl<T> Iterator for Leftovers<T> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.front.take().or_else(|| self.back.take())
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = usize::from(self.front.is_some()) + usize::from(self.back.is_some());
+        (len, Some(len))
+    }
+}
+
+impl<T> DoubleEndedIterator for Leftovers<T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.back.take().or_else(|| self.front.take())
+    }
+}
+
+impl<T> ExactSizeIterator for Leftovers<T> {}
+
+impl<T> FusedIterator for Leftovers<T> {}
@@ -0,0 +1,56 @@
+use super::*;
+
+#[test]
+fn no_leftovers() {
+    let mut iter = [0, 1, 2].into_iter();
+    let (out, leftovers) = with_double_ended_peekable(&mut iter, |iter| iter.next());
+
+    assert_eq!(out, Some(0));
+    assert!(leftovers.is_empty());
+    assert_eq!(leftovers.len(), 0);
+    assert_eq!(iter.as_slice(), [1, 2]);
+}
+
+#[test]
+fn leftovers_from_both_ends() {
+    let mut iter = [0, 1, 2, 3].into_iter();
+    let ((), leftovers) = with_double_ended_peekable(&mut iter, |iter| {
+        assert_eq!(iter.next_if_eq(&42), None);
+        assert_eq!(iter.next_back_if_eq(&42), None);
+    });
+
+    assert_eq!(leftovers.front(), Some(&0));
+    assert_eq!(leftovers.back(), Some(&3));
+    assert_eq!(leftovers.len(), 2);
+    assert_eq!(iter.as_slice(), [1, 2]);
+    assert_eq!(leftovers.clone().collect::<Vec<_>>(), [0, 3]);
+    assert_eq!(leftovers.clone().rev().collect::<Vec<_>>(), [3, 0]);
+    assert_eq!(leftovers.into_parts(), (Some(0), Some(3)));
+}
+
+#[test]
+fn leftover_after_meeting_in_the_middle() {
+    let mut iter = [0, 1].into_iter();
+    let ((), leftovers) = with_double_ended_peekable(&mut iter, |iter| {
+        assert_eq!(iter.peek_back(), Some(&1));
+        assert_eq!(iter.next(), Some(0));
+        // The front is peeked past the end of the underlying iterator
+        assert_eq!(iter.peek(), Some(&1));
+    });
+
+    assert_eq!(leftovers.into_parts(), (None, Some(1)));
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn leftovers_restore_the_sequence() {
+    let mut iter = 0..10;
+    let ((), leftovers) = with_double_ended_peekable(&mut iter, |iter| {
+        while iter.next_if(|&x| x < 3).is_some() {}
+        while iter.next_back_if(|&x| x > 7).is_some() {}
+    });
+
+    let (front, back) = leftovers.into_parts();
+    let restored = front.into_iter().chain(iter).chain(back);
+    assert!(restored.eq(3..8));
+}
@@ -1,4 +1,4 @@
-use std::collections::hash_map::DefaultHasher;
+use std::{collections::hash_map::DefaultHasher, rc::Rc};
 
 use super::*;
 
@@ -578,3 +578,668 @@ fn hash() {
 
     assert_eq!(hash, expected_hash);
 }
+
+#[test]
+fn size_hint_all_states() {
+    let states = || {
+        [
+            MaybePeeked::Unpeeked,
+            MaybePeeked::Peeked(None),
+            MaybePeeked::Peeked(Some(42)),
+        ]
+    };
+
+    for front in states() {
+        for back in states() {
+            let additional = usize::from(matches!(front, MaybePeeked::Peeked(Some(_))))
+                + usize::from(matches!(back, MaybePeeked::Peeked(Some(_))));
+
+            let iter = DoubleEndedPeekable {
+                iter: 0..3,
+                front,
+                back,
+            };
+            assert_eq!(
+                iter.size_hint(),
+                (3 + additional, Some(3 + additional)),
+                "front: {front:?}, back: {back:?}",
+            );
+            assert_eq!(iter.len(), 3 + additional);
+            assert!(!iter.is_empty());
+
+            let iter = DoubleEndedPeekable {
+                iter: 0..0,
+                front,
+                back,
+            };
+            assert_eq!(iter.size_hint(), (additional, Some(additional)));
+            assert_eq!(iter.is_empty(), additional == 0);
+            assert_eq!(iter.count(), additional);
+
+            let iter = DoubleEndedPeekable {
+                iter: 0..,
+                front,
+                back,
+            };
+            assert_eq!(iter.size_hint(), (usize::MAX, None));
+
+            let iter = DoubleEndedPeekable {
+                iter: (0..usize::MAX).filter(|_| true),
+                front,
+                back,
+            };
+            assert_eq!(
+                iter.size_hint(),
+                (additional, usize::MAX.checked_add(additional)),
+            );
+        }
+    }
+}
+
+#[test]
+fn size_hint_after_peeking_past_end() {
+    let mut iter = [0, 1].into_iter().double_ended_peekable();
+    assert_eq!(iter.peek_back(), Some(&1));
+    assert_eq!(iter.next(), Some(0));
+    assert_eq!(iter.peek(), Some(&1));
+    assert_eq!(iter.front, MaybePeeked::Peeked(None));
+    assert_eq!(iter.size_hint(), (1, Some(1)));
+    assert_eq!(iter.len(), 1);
+
+    assert_eq!(iter.next(), Some(1));
+    assert_eq!(iter.peek_back(), None);
+    assert_eq!(iter.peek(), None);
+    assert_eq!(iter.size_hint(), (0, Some(0)));
+    assert!(iter.is_empty());
+}
+
+#[test]
+fn fused() {
+    fn assert_fused<I: FusedIterator>(_: &I) {}
+
+    let mut iter = [0].into_iter().double_ended_peekable();
+    assert_fused(&iter);
+    assert_eq!(iter.next(), Some(0));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_back(), None);
+}
+
+/// All the combinations of peeked states that can be reached from the given number of items
+/// in the underlying iterator.
+fn peeked_states(len: i32) -> impl Iterator<Item = DoubleEndedPeekable<core::ops::Range<i32>>> {
+    let states = || {
+        [
+            MaybePeeked::Unpeeked,
+            MaybePeeked::Peeked(None),
+            MaybePeeked::Peeked(Some(-1)),
+        ]
+    };
+
+    states()
+        .into_iter()
+        .flat_map(move |front| states().into_iter().map(move |back| (front, back)))
+        .filter(move |(front, back)| {
+            // An iterator peeked past its end must be exhausted
+            len == 0 || !(front == &MaybePeeked::Peeked(None) || back == &MaybePeeked::Peeked(None))
+        })
+        .map(move |(front, back)| DoubleEndedPeekable {
+            iter: 0..len,
+            front: front.map(|item| item - 1),
+            back: back.map(|item| item + 100),
+        })
+}
+
+#[test]
+fn internal_iteration_matches_naive() {
+    for len in 0..4 {
+        for iter in peeked_states(len) {
+            let mut naive = iter.clone();
+            let expected = core::iter::from_fn(|| naive.next()).collect::<Vec<_>>();
+            let ctx = format!("{iter:?}");
+
+            let folded = iter.clone().fold(Vec::new(), |mut acc, item| {
+                acc.push(item);
+                acc
+            });
+            assert_eq!(folded, expected, "{ctx}");
+
+            let rfolded = iter.clone().rfold(Vec::new(), |mut acc, item| {
+                acc.push(item);
+                acc
+            });
+            assert!(rfolded.iter().eq(expected.iter().rev()), "{ctx}");
+
+            assert_eq!(iter.clone().count(), expected.len(), "{ctx}");
+            assert_eq!(iter.clone().last(), expected.last().copied(), "{ctx}");
+            assert_eq!(iter.clone().sum::<i32>(), expected.iter().sum(), "{ctx}");
+
+            for n in 0..7 {
+                let mut nth = iter.clone();
+                let mut naive = iter.clone();
+                let expected = (0..=n).filter_map(|_| naive.next()).nth(n);
+                assert_eq!(nth.nth(n), expected, "{ctx}, n: {n}");
+                assert!(nth.eq(naive), "{ctx}, n: {n}");
+
+                let mut nth_back = iter.clone();
+                let mut naive = iter.clone();
+                let expected = (0..=n).filter_map(|_| naive.next_back()).nth(n);
+                assert_eq!(nth_back.nth_back(n), expected, "{ctx}, n: {n}");
+                assert!(nth_back.eq(naive), "{ctx}, n: {n}");
+            }
+        }
+    }
+}
+
+#[test]
+fn internal_iteration_is_forwarded() {
+    use std::{cell::Cell, rc::Rc};
+
+    /// An iterator that counts the calls to `next` and `next_back`.
+    #[derive(Clone)]
+    struct Tracked(core::ops::Range<i32>, Rc<Cell<usize>>);
+
+    impl Iterator for Tracked {
+        type Item = i32;
+
+        fn next(&mut self) -> Option<i32> {
+            self.1.set(self.1.get() + 1);
+            self.0.next()
+        }
+
+        fn fold<B, F: FnMut(B, i32) -> B>(self, init: B, f: F) -> B {
+            self.0.fold(init, f)
+        }
+
+        fn nth(&mut self, n: usize) -> Option<i32> {
+            self.0.nth(n)
+        }
+    }
+
+    impl DoubleEndedIterator for Tracked {
+        fn next_back(&mut self) -> Option<i32> {
+            self.1.set(self.1.get() + 1);
+            self.0.next_back()
+        }
+
+        fn rfold<B, F: FnMut(B, i32) -> B>(self, init: B, f: F) -> B {
+            self.0.rfold(init, f)
+        }
+
+        fn nth_back(&mut self, n: usize) -> Option<i32> {
+            self.0.nth_back(n)
+        }
+    }
+
+    let calls = Rc::new(Cell::new(0));
+    let mut iter = Tracked(0..100, Rc::clone(&calls)).double_ended_peekable();
+    assert_eq!(iter.peek(), Some(&0));
+    assert_eq!(iter.peek_back(), Some(&99));
+    assert_eq!(calls.get(), 2);
+
+    assert_eq!(iter.clone().sum::<i32>(), 4950);
+    assert_eq!(iter.clone().rev().sum::<i32>(), 4950);
+    assert_eq!(iter.nth(10), Some(10));
+    assert_eq!(iter.nth_back(10), Some(89));
+    assert_eq!(calls.get(), 4);
+}
+
+#[test]
+fn into_parts() {
+    let mut iter = [0, 1, 2, 3].into_iter().double_ended_peekable();
+    let (front, inner, back) = iter.clone().into_parts();
+    assert_eq!(front, None);
+    assert_eq!(inner.as_slice(), [0, 1, 2, 3]);
+    assert_eq!(back, None);
+
+    assert_eq!(iter.peek(), Some(&0));
+    assert_eq!(iter.peek_back(), Some(&3));
+    let (front, inner, back) = iter.clone().into_parts();
+    assert_eq!(front, Some(Some(0)));
+    assert_eq!(inner.as_slice(), [1, 2]);
+    assert_eq!(back, Some(Some(3)));
+
+    let mut iter = [0].into_iter().double_ended_peekable();
+    assert_eq!(iter.peek_back(), Some(&0));
+    assert_eq!(iter.peek(), Some(&0));
+    let (front, inner, back) = iter.into_parts();
+    assert_eq!(front, Some(None));
+    assert_eq!(inner.as_slice(), []);
+    assert_eq!(back, Some(Some(0)));
+}
+
+#[test]
+fn from_parts_round_trip() {
+    let mut iter = (0..4).double_ended_peekable();
+    assert_eq!(iter.peek(), Some(&0));
+    let (front, inner, back) = iter.clone().into_parts();
+    let rebuilt = DoubleEndedPeekable::from_parts(front, inner, back);
+    assert_eq!(rebuilt, iter);
+    assert_eq!(rebuilt.collect::<Vec<_>>(), [0, 1, 2, 3]);
+
+    // The end of the iteration peeked from the front is kept
+    let mut iter = (0..1).double_ended_peekable();
+    assert_eq!(iter.peek_back(), Some(&0));
+    assert_eq!(iter.peek(), Some(&0));
+    let (front, inner, back) = iter.clone().into_parts();
+    let rebuilt = DoubleEndedPeekable::from_parts(front, inner, back);
+    assert_eq!(rebuilt.front, MaybePeeked::Peeked(None));
+    assert_eq!(rebuilt, iter);
+}
+
+#[test]
+fn into_inner() {
+    let mut iter = (0..5).double_ended_peekable();
+    assert_eq!(iter.next(), Some(0));
+    assert_eq!(iter.peek(), Some(&1));
+    assert_eq!(iter.peek_back(), Some(&4));
+    let mut inner = iter.into_inner();
+    assert_eq!(inner.size_hint(), (4, Some(4)));
+    assert_eq!(inner.next(), Some(1));
+    assert_eq!(inner.next_back(), Some(4));
+    assert_eq!(inner.collect::<Vec<_>>(), [2, 3]);
+
+    // The end of the iteration peeked from the front is not an item
+    let mut iter = (0..1).double_ended_peekable();
+    assert_eq!(iter.peek_back(), Some(&0));
+    assert_eq!(iter.peek(), Some(&0));
+    assert!(iter.clone().into_inner().eq([0]));
+    assert!(iter.into_inner().rev().eq([0]));
+}
+
+#[test]
+fn try_push() {
+    let mut iter = (0..3).double_ended_peekable();
+    assert_eq!(iter.try_push_front(-1), Ok(()));
+    assert_eq!(iter.try_push_front(-2), Err(-2));
+    assert_eq!(iter.try_push_back(3), Ok(()));
+    assert_eq!(iter.try_push_back(4), Err(4));
+    assert_eq!(iter.clone().collect::<Vec<_>>(), [-1, 0, 1, 2, 3]);
+    assert_eq!(iter.clone().rev().collect::<Vec<_>>(), [3, 2, 1, 0, -1]);
+
+    // Pushed items are reached from the opposite end
+    let mut iter = (0..0).double_ended_peekable();
+    assert_eq!(iter.try_push_front(0), Ok(()));
+    assert_eq!(iter.peek_back(), Some(&0));
+    assert_eq!(iter.next_back(), Some(0));
+    assert_eq!(iter.try_push_back(1), Ok(()));
+    assert_eq!(iter.peek(), Some(&1));
+    assert_eq!(iter.next(), Some(1));
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn try_push_after_peeking_past_end() {
+    let mut iter = [0].into_iter().double_ended_peekable();
+    assert_eq!(iter.peek_back(), Some(&0));
+    assert_eq!(iter.peek(), Some(&0));
+    assert_eq!(iter.front, MaybePeeked::Peeked(None));
+
+    // The slot holding the end of the iteration is considered empty
+    assert_eq!(iter.try_push_front(1), Ok(()));
+    assert_eq!(iter.peek(), Some(&1));
+    assert_eq!(iter.size_hint(), (2, Some(2)));
+    assert_eq!(iter.collect::<Vec<_>>(), [1, 0]);
+
+    let mut iter = [0].into_iter().double_ended_peekable();
+    assert_eq!(iter.peek(), Some(&0));
+    assert_eq!(iter.peek_back(), Some(&0));
+    assert_eq!(iter.try_push_back(1), Ok(()));
+    assert_eq!(iter.peek_back(), Some(&1));
+    assert_eq!(iter.rev().collect::<Vec<_>>(), [1, 0]);
+}
+
+#[test]
+fn try_push_after_end_of_iteration() {
+    let mut iter = [0, 1].into_iter().double_ended_peekable();
+    assert_eq!(iter.peek_back(), Some(&1));
+    assert_eq!(iter.next(), Some(0));
+    // The front slot holds the end of the iteration, peeking falls back to the back slot
+    assert_eq!(iter.peek(), Some(&1));
+    assert_eq!(iter.front, MaybePeeked::Peeked(None));
+
+    assert_eq!(iter.try_push_front(2), Ok(()));
+    assert_eq!(iter.peek(), Some(&2));
+    assert_eq!(iter.peek_back(), Some(&1));
+    assert_eq!(iter.next_back(), Some(1));
+    // Once the back slot is consumed, the pushed item is reached from the back
+    assert_eq!(iter.peek_back(), Some(&2));
+    assert_eq!(iter.next(), Some(2));
+    assert_eq!(iter.peek_back(), None);
+
+    // Pushing makes even a fused iterator return items after `None`
+    let mut iter = [0].into_iter().double_ended_peekable();
+    assert_eq!(iter.next_back(), Some(0));
+    assert_eq!(iter.peek_back(), None);
+    assert_eq!(iter.try_push_back(1), Ok(()));
+    assert_eq!(iter.peek(), Some(&1));
+    assert_eq!(iter.next(), Some(1));
+    assert_eq!(iter.next_back(), None);
+}
+
+#[test]
+fn peek_front_back() {
+    let mut iter = (0..4).double_ended_peekable();
+    assert_eq!(iter.peek_front_back(), Ends::Pair(&0, &3));
+    assert_eq!(iter.front, MaybePeeked::Peeked(Some(0)));
+    assert_eq!(iter.back, MaybePeeked::Peeked(Some(3)));
+    assert_eq!(iter.collect::<Vec<_>>(), [0, 1, 2, 3]);
+
+    // The last item, already peeked from the back, is reached from the front as well
+    let mut iter = (0..2).double_ended_peekable();
+    assert_eq!(iter.peek_back(), Some(&1));
+    assert_eq!(iter.next(), Some(0));
+    assert_eq!(iter.peek_front_back(), Ends::Single(&1));
+    assert_eq!(iter.len(), 1);
+    assert_eq!(iter.next_back(), Some(1));
+
+    let mut iter = (0..0).double_ended_peekable();
+    assert_eq!(iter.peek_front_back(), Ends::Empty);
+}
+
+#[test]
+fn peek_front_back_mut() {
+    let mut iter = (0..3).double_ended_peekable();
+    if let Ends::Pair(front, back) = iter.peek_front_back_mut() {
+        *front += 10;
+        *back += 20;
+    }
+    assert_eq!(iter.peek(), Some(&10));
+    assert_eq!(iter.peek_back(), Some(&22));
+
+    let mut iter = [0].into_iter().double_ended_peekable();
+    if let Ends::Single(item) = iter.peek_front_back_mut() {
+        *item = 5;
+    }
+    assert_eq!(iter.peek_back(), Some(&5));
+    assert_eq!(iter.collect::<Vec<_>>(), [5]);
+}
+
+#[test]
+fn next_front_back() {
+    let mut iter = (0..5).double_ended_peekable();
+    assert_eq!(iter.peek_back(), Some(&4));
+    assert_eq!(iter.next_front_back(), Ends::Pair(0, 4));
+    assert_eq!(iter.next_front_back(), Ends::Pair(1, 3));
+    assert_eq!(iter.next_front_back(), Ends::Single(2));
+    assert_eq!(iter.next_front_back(), Ends::Empty);
+
+    // The last item is in the front slot, while the back one holds the end of the iteration
+    let mut iter = (0..1).double_ended_peekable();
+    assert_eq!(iter.peek(), Some(&0));
+    assert_eq!(iter.peek_back(), Some(&0));
+    assert_eq!(iter.back, MaybePeeked::Peeked(None));
+    assert_eq!(iter.next_front_back(), Ends::Single(0));
+    assert_eq!(iter.next_front_back(), Ends::Empty);
+}
+
+#[test]
+fn try_next_front_back_if() {
+    let mut iter = [0, 1, 2, 1, 3].into_iter().double_ended_peekable();
+    assert_eq!(iter.try_next_front_back_if(|_, _| true), Ok((0, 3)));
+    assert_eq!(iter.try_next_front_back_if_eq(&1, &1), Ok((1, 1)));
+    assert_eq!(
+        iter.try_next_front_back_if(|_, _| true),
+        Err(Ends::Single(&2))
+    );
+    assert_eq!(iter.peek(), Some(&2));
+    assert_eq!(iter.peek_back(), Some(&2));
+    assert_eq!(iter.next_back(), Some(2));
+    assert_eq!(iter.try_next_front_back_if(|_, _| true), Err(Ends::Empty));
+    assert_eq!(iter.next(), None);
+
+    let mut iter = (0..4).double_ended_peekable();
+    let mut calls = 0;
+    assert_eq!(
+        iter.try_next_front_back_if(|_, _| {
+            calls += 1;
+            false
+        }),
+        Err(Ends::Pair(&0, &3))
+    );
+    assert_eq!(calls, 1);
+    assert_eq!(iter.collect::<Vec<_>>(), [0, 1, 2, 3]);
+}
+
+#[test]
+fn next_ends_with() {
+    let mut iter = (0..6).double_ended_peekable();
+    assert_eq!(iter.next_ends_with(|_, _| Take::Front), (Some(0), None));
+    assert_eq!(iter.back, MaybePeeked::Peeked(Some(5)));
+    assert_eq!(iter.next_ends_with(|_, _| Take::Back), (None, Some(5)));
+    assert_eq!(iter.front, MaybePeeked::Peeked(Some(1)));
+    assert_eq!(iter.next_ends_with(|_, _| Take::None), (None, None));
+    assert_eq!(
+        iter.next_ends_with(|&front, &back| {
+            assert_eq!((front, back), (1, 4));
+            Take::Both
+        }),
+        (Some(1), Some(4))
+    );
+    assert_eq!(iter.collect::<Vec<_>>(), [2, 3]);
+
+    for (take, expected) in [
+        (Take::Front, (Some(0), None)),
+        (Take::Back, (None, Some(0))),
+        (Take::Both, (Some(0), None)),
+        (Take::None, (None, None)),
+    ] {
+        let mut iter = [0].into_iter().double_ended_peekable();
+        assert_eq!(
+            iter.next_ends_with(|front, back| {
+                assert_eq!(front, back);
+                take
+            }),
+            expected
+        );
+        assert_eq!(iter.len(), usize::from(take == Take::None));
+    }
+
+    let mut iter = (0..0).double_ended_peekable();
+    assert_eq!(
+        iter.next_ends_with(|_, _| unreachable!("no items to look at")),
+        (None, None)
+    );
+}
+
+#[test]
+fn two_pointer() {
+    // Taking only the back keeps the front item for the next step
+    let mut iter = (0..6).double_ended_peekable();
+    let visited = iter.two_pointer(Vec::new(), |visited, &front, &back| {
+        visited.push((front, back));
+        if visited.len() % 2 == 0 {
+            Take::Back
+        } else {
+            Take::Both
+        }
+    });
+    assert_eq!(visited, [(0, 5), (1, 4), (1, 3), (2, 2)]);
+    assert_eq!(iter.next(), None);
+
+    let mut iter = (0..10).double_ended_peekable();
+    let steps = iter.two_pointer(0, |steps, &front, _| {
+        *steps += 1;
+        if front < 3 {
+            Take::Front
+        } else {
+            Take::None
+        }
+    });
+    assert_eq!(steps, 4);
+    assert_eq!(iter.peek(), Some(&3));
+    assert_eq!(iter.peek_back(), Some(&9));
+}
+
+#[test]
+fn next_if_map() {
+    let mut iter = [1, -2, 3].into_iter().double_ended_peekable();
+    let positive = |item: i32| u32::try_from(item).map_err(|_| item);
+
+    assert_eq!(iter.next_if_map(positive), Some(1));
+    assert_eq!(iter.next_if_map(positive), None);
+    assert_eq!(iter.front, MaybePeeked::Peeked(Some(-2)));
+    assert_eq!(iter.next_back_if_map(positive), Some(3));
+    assert_eq!(iter.next_back_if_map(positive), None);
+    // The item peeked from the front is given to the back
+    assert_eq!(iter.front, MaybePeeked::Unpeeked);
+    assert_eq!(iter.back, MaybePeeked::Peeked(Some(-2)));
+    assert_eq!(iter.peek(), Some(&-2));
+
+    // The function can give back a different item
+    assert_eq!(iter.next_if_map(|item| Err::<(), _>(item * 10)), None);
+    assert_eq!(iter.collect::<Vec<_>>(), [-20]);
+
+    let mut iter = (0..0).double_ended_peekable();
+    assert_eq!(iter.next_if_map(|_| Ok(())), None);
+    assert_eq!(iter.front, MaybePeeked::Peeked(None));
+    assert_eq!(iter.next_back_if_map(|_| Ok(())), None);
+    assert_eq!(iter.back, MaybePeeked::Peeked(None));
+}
+
+#[test]
+fn try_next_if() {
+    let mut iter = (0..4).double_ended_peekable();
+    assert_eq!(iter.try_next_if_eq(&0), Ok(0));
+    assert_eq!(iter.try_next_if_eq(&0), Err(Rejected::Mismatch(&1)));
+    assert_eq!(iter.front, MaybePeeked::Peeked(Some(1)));
+    assert_eq!(iter.try_next_back_if_eq(&3), Ok(3));
+    assert_eq!(
+        iter.try_next_back_if(|_| false),
+        Err(Rejected::Mismatch(&2))
+    );
+    assert_eq!(iter.back, MaybePeeked::Peeked(Some(2)));
+    assert_eq!(iter.collect::<Vec<_>>(), [1, 2]);
+
+    // The only item left can be rejected from both the ends
+    let mut iter = [0].into_iter().double_ended_peekable();
+    assert_eq!(
+        iter.try_next_back_if(|_| false),
+        Err(Rejected::Mismatch(&0))
+    );
+    assert_eq!(iter.try_next_if(|_| false), Err(Rejected::Mismatch(&0)));
+    assert_eq!(iter.try_next_back_if(|_| true), Ok(0));
+    let rejected = iter.try_next_if(|_| true).unwrap_err();
+    assert!(rejected.is_exhausted());
+    assert_eq!(rejected.mismatch(), None);
+    assert_eq!(iter.try_next_back_if(|_| true), Err(Rejected::Exhausted));
+}
+
+#[test]
+fn trim_while() {
+    let mut iter = [0, 0, 1, 0, 2, 0].into_iter().double_ended_peekable();
+    assert_eq!(
+        iter.trim_while(|&item| item == 0).collect::<Vec<_>>(),
+        [1, 0, 2]
+    );
+
+    let mut iter = [0, 1, 0].into_iter().double_ended_peekable();
+    iter.trim_start_while(|&item| item == 0);
+    assert_eq!(iter.front, MaybePeeked::Peeked(Some(1)));
+    iter.trim_end_while(|&item| item == 0);
+    assert_eq!(iter.back, MaybePeeked::Peeked(Some(1)));
+    assert_eq!(iter.len(), 1);
+
+    let mut calls = Vec::new();
+    let mut iter = [0, 0, 0].into_iter().double_ended_peekable();
+    iter.trim_while(|&item| {
+        calls.push(item);
+        item == 0
+    });
+    assert_eq!(calls, [0, 0, 0]);
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_back(), None);
+}
+
+#[test]
+fn strip_ends() {
+    let mut iter = (0..6).double_ended_peekable();
+    let mut calls = 0;
+    assert_eq!(
+        iter.strip_ends_if(|&front, &back| {
+            calls += 1;
+            front < 1 || back > 4
+        }),
+        1
+    );
+    assert_eq!(calls, 2);
+    assert_eq!(iter.peek_front_back(), Ends::Pair(&1, &4));
+
+    let pairs = [(0, 0), (1, 2)];
+    let mut iter = [1, 0, 1, 5, 2, 0, 2].into_iter().double_ended_peekable();
+    assert_eq!(iter.strip_matching(&pairs, 0), 0);
+    assert_eq!(iter.strip_matching(&pairs, 2), 2);
+    assert_eq!(iter.strip_matching(&pairs, 2), 1);
+    // A single item left in the middle is never consumed
+    assert_eq!(iter.strip_matching(&[(5, 5)], usize::MAX), 0);
+    assert_eq!(iter.collect::<Vec<_>>(), [5]);
+
+    let mut iter = (0..0).double_ended_peekable();
+    assert_eq!(iter.strip_ends_if(|_, _| true), 0);
+}
+
+#[test]
+fn reversed() {
+    let mut iter = (0..4).double_ended_peekable();
+    assert_eq!(iter.peek_back(), Some(&3));
+    let mut reversed = iter.reversed();
+    assert_eq!(reversed.front, MaybePeeked::Peeked(Some(3)));
+    assert_eq!(reversed.back, MaybePeeked::Unpeeked);
+    assert_eq!(reversed.next_if_eq(&3), Some(3));
+    assert_eq!(reversed.peek_back(), Some(&0));
+    let iter = reversed.unreversed();
+    assert_eq!(iter.front, MaybePeeked::Peeked(Some(0)));
+    assert_eq!(iter.collect::<Vec<_>>(), [0, 1, 2]);
+
+    // The end of the iteration peeked from the back becomes the one peeked from the front
+    let mut iter = (0..1).double_ended_peekable();
+    assert_eq!(iter.peek(), Some(&0));
+    assert_eq!(iter.peek_back(), Some(&0));
+    let reversed = iter.clone().reversed();
+    assert_eq!(reversed.front, MaybePeeked::Peeked(None));
+    assert_eq!(reversed.back, MaybePeeked::Peeked(Some(0)));
+    assert_eq!(reversed.size_hint(), (1, Some(1)));
+    assert!(reversed.clone().eq([0]));
+    assert_eq!(reversed.unreversed(), iter);
+}
+
+#[test]
+fn from_peekable() {
+    // Not peeked
+    let peekable = (0..3).peekable();
+    assert!(DoubleEndedPeekable::from(peekable).eq(0..3));
+
+    // Peeked from the front, then consumed from either end
+    let mut peekable = (0..3).peekable();
+    assert_eq!(peekable.peek(), Some(&0));
+    let iter = DoubleEndedPeekable::from(peekable);
+    assert!(iter.clone().eq(0..3));
+    assert!(iter.clone().rev().eq((0..3).rev()));
+
+    let mut iter = iter;
+    assert_eq!(iter.peek_back(), Some(&2));
+    assert_eq!(iter.next_back(), Some(2));
+    assert_eq!(iter.next_back(), Some(1));
+    assert_eq!(iter.peek_back(), Some(&0));
+    assert_eq!(iter.peek(), Some(&0));
+    assert_eq!(iter.next(), Some(0));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_back(), None);
+
+    // Peeked past the end
+    let mut peekable = (0..0).peekable();
+    assert_eq!(peekable.peek(), None);
+    let mut iter = DoubleEndedPeekable::from(peekable);
+    assert_eq!(iter.peek_back(), None);
+    assert_eq!(iter.next(), None);
+
+    // Items are neither duplicated nor dropped
+    let item = Rc::new(());
+    let mut peekable = vec![Rc::clone(&item); 2].into_iter().peekable();
+    assert!(peekable.peek().is_some());
+    let mut iter = DoubleEndedPeekable::from(peekable);
+    assert!(iter.peek_back().is_some());
+    assert_eq!(Rc::strong_count(&item), 3);
+    assert_eq!(iter.count(), 2);
+    assert_eq!(Rc::strong_count(&item), 1);
+}
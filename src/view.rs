@@ -0,0 +1,192 @@
+#[cfg(test)]
+mod tests;
+
+use core::{
+    fmt::{self, Debug},
+    iter::FusedIterator,
+};
+
+use crate::DoubleEndedPeekable;
+
+/// A view over the front of a [`DoubleEndedPeekable`], usable as a peekable forward iterator.
+///
+/// The view shares the peeked items with the underlying adapter, therefore nothing is lost when
+/// the view is dropped.
+///
+/// This `struct` is created by [`DoubleEndedPeekable::front_view`].
+pub struct FrontView<'a, I>
+where
+    I: Iterator,
+{
+    peekable: &'a mut DoubleEndedPeekable<I>,
+}
+
+impl<'a, I> FrontView<'a, I>
+where
+    I: Iterator,
+{
+    #[inline]
+    pub(crate) fn new(peekable: &'a mut DoubleEndedPeekable<I>) -> Self {
+        Self { peekable }
+    }
+
+    /// Returns a reference to the `next()` value without advancing the iterator.
+    ///
+    /// See [`DoubleEndedPeekable::peek`] for more information.
+    #[inline]
+    pub fn peek(&mut self) -> Option<&I::Item> {
+        self.peekable.peek()
+    }
+
+    /// Returns a mutable reference to the `next()` value without advancing the iterator.
+    ///
+    /// See [`DoubleEndedPeekable::peek_mut`] for more information.
+    #[inline]
+    pub fn peek_mut(&mut self) -> Option<&mut I::Item> {
+        self.peekable.peek_mut()
+    }
+
+    /// Consumes and returns the next value of this iterator if a condition is true.
+    ///
+    /// See [`DoubleEndedPeekable::next_if`] for more information.
+    #[inline]
+    pub fn next_if(&mut self, func: impl FnOnce(&I::Item) -> bool) -> Option<I::Item> {
+        self.peekable.next_if(func)
+    }
+
+    /// Consumes and returns the next item if it is equal to `expected`.
+    ///
+    /// See [`DoubleEndedPeekable::next_if_eq`] for more information.
+    #[inline]
+    pub fn next_if_eq<T>(&mut self, expected: &T) -> Option<I::Item>
+    where
+        T: ?Sized,
+        I::Item: PartialEq<T>,
+    {
+        self.peekable.next_if_eq(expected)
+    }
+}
+
+impl<I> Iterator for FrontView<'_, I>
+where
+    I: Iterator,
+{
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.peekable.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.peekable.size_hint()
+    }
+}
+
+impl<I> ExactSizeIterator for FrontView<'_, I> where I: ExactSizeIterator {}
+
+impl<I> FusedIterator for FrontView<'_, I> where I: FusedIterator {}
+
+impl<I> Debug for FrontView<'_, I>
+where
+    I: Iterator + Debug,
+    I::Item: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("FrontView").field(&self.peekable).finish()
+    }
+}
+
+/// A view over the back of a [`DoubleEndedPeekable`], usable as a peekable forward iterator that
+/// returns the items from the last one.
+///
+/// The view shares the peeked items with the underlying adapter, therefore nothing is lost when
+/// the view is dropped.
+///
+/// This `struct` is created by [`DoubleEndedPeekable::back_view`].
+pub struct BackView<'a, I>
+where
+    I: Iterator,
+{
+    peekable: &'a mut DoubleEndedPeekable<I>,
+}
+
+impl<'a, I> BackView<'a, I>
+where
+    I: DoubleEndedIterator,
+{
+    #[inline]
+    pub(crate) fn new(peekable: &'a mut DoubleEndedPeekable<I>) -> Self {
+        Self { peekable }
+    }
+
+    /// Returns a reference to the `next()` value, which is the _next back_ value of the
+    /// underlying adapter, without advancing the iterator.
+    ///
+    /// See [`DoubleEndedPeekable::peek_back`] for more information.
+    #[inline]
+    pub fn peek(&mut self) -> Option<&I::Item> {
+        self.peekable.peek_back()
+    }
+
+    /// Returns a mutable reference to the `next()` value, which is the _next back_ value of the
+    /// underlying adapter, without advancing the iterator.
+    ///
+    /// See [`DoubleEndedPeekable::peek_back_mut`] for more information.
+    #[inline]
+    pub fn peek_mut(&mut self) -> Option<&mut I::Item> {
+        self.peekable.peek_back_mut()
+    }
+
+    /// Consumes and returns the next value of this iterator if a condition is true.
+    ///
+    /// See [`DoubleEndedPeekable::next_back_if`] for more information.
+    #[inline]
+    pub fn next_if(&mut self, func: impl FnOnce(&I::Item) -> bool) -> Option<I::Item> {
+        self.peekable.next_back_if(func)
+    }
+
+    /// Consumes and returns the next item if it is equal to `expected`.
+    ///
+    /// See [`DoubleEndedPeekable::next_back_if_eq`] for more information.
+    #[inline]
+    pub fn next_if_eq<T>(&mut self, expected: &T) -> Option<I::Item>
+    where
+        T: ?Sized,
+        I::Item: PartialEq<T>,
+    {
+        self.peekable.next_back_if_eq(expected)
+    }
+}
+
+impl<I> Iterator for BackView<'_, I>
+where
+    I: DoubleEndedIterator,
+{
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.peekable.next_back()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.peekable.size_hint()
+    }
+}
+
+impl<I> ExactSizeIterator for BackView<'_, I> where I: ExactSizeIterator + DoubleEndedIterator {}
+
+impl<I> FusedIterator for BackView<'_, I> where I: FusedIterator + DoubleEndedIterator {}
+
+impl<I> Debug for BackView<'_, I>
+where
+    I: Iterator + Debug,
+    I::Item: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("BackView").field(&self.peekable).finish()
+    }
+}
@@ -0,0 +1,62 @@
+use crate::{DoubleEndedPeekableExt, PeekFront};
+
+/// A forward parser that only knows about peeking.
+fn parse_number(view: &mut impl PeekFront<Item = char>) -> u32 {
+    let mut number = 0;
+    while let Some(c) = view.next_if(char::is_ascii_digit) {
+        number = number * 10 + c.to_digit(10).unwrap();
+    }
+    number
+}
+
+#[test]
+fn front_view() {
+    let mut iter = "12+34".chars().double_ended_peekable();
+    let mut view = iter.front_view();
+    assert_eq!(view.size_hint(), (2, Some(5)));
+    assert_eq!(view.peek(), Some(&'1'));
+    assert_eq!(view.next_if_eq(&'1'), Some('1'));
+    assert_eq!(view.next_if(char::is_ascii_punctuation), None);
+    *view.peek_mut().unwrap() = '3';
+    assert_eq!(view.next(), Some('3'));
+    assert_eq!(view.peek(), Some(&'+'));
+
+    // The item peeked from the view is not lost
+    assert_eq!(iter.next(), Some('+'));
+    assert_eq!(
+        format!("{:?}", iter.front_view()).get(..10),
+        Some("FrontView(")
+    );
+}
+
+#[test]
+fn back_view() {
+    let mut iter = "12+34".chars().double_ended_peekable();
+    let mut view = iter.back_view();
+    assert_eq!(view.peek(), Some(&'4'));
+    assert_eq!(view.next_if_eq(&'4'), Some('4'));
+    assert_eq!(view.next_if(char::is_ascii_punctuation), None);
+    *view.peek_mut().unwrap() = '5';
+    assert_eq!(view.next(), Some('5'));
+    assert_eq!(view.peek(), Some(&'+'));
+
+    assert_eq!(iter.next_back(), Some('+'));
+    assert_eq!(iter.peek(), Some(&'1'));
+    let mut view = iter.back_view();
+    assert_eq!(view.size_hint(), (2, Some(2)));
+    assert_eq!(view.next(), Some('2'));
+    assert_eq!(view.peek(), Some(&'1'));
+    assert_eq!(iter.peek_back(), Some(&'1'));
+}
+
+#[test]
+fn views_can_be_passed_to_forward_parsers() {
+    let mut iter = "12+34".chars().double_ended_peekable();
+    assert_eq!(parse_number(&mut iter.front_view()), 12);
+    // The item the parser stopped on is still there
+    assert_eq!(iter.peek(), Some(&'+'));
+    assert_eq!(parse_number(&mut iter.back_view()), 43);
+    assert_eq!(iter.peek_back(), Some(&'+'));
+    assert_eq!(iter.next(), Some('+'));
+    assert_eq!(iter.next(), None);
+}